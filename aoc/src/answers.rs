@@ -0,0 +1,78 @@
+/*
+Recorded answers for each day, part and input variant.
+
+These are checked by the tests in tests/answers.rs, so any change
+to a solver which changes one of its answers is caught.
+*/
+
+pub struct Answer {
+    pub day: u32,
+    pub part: u8,
+    // input variant in the archive, such as "real" or "example"
+    pub input: &'static str,
+    pub expected: &'static str,
+}
+
+const fn answer(day: u32, part: u8, input: &'static str, expected: &'static str) -> Answer {
+    Answer { day, part, input, expected }
+}
+
+pub const ANSWERS: &[Answer] = &[
+    answer(1, 1, "real", "69206"),
+    answer(1, 2, "real", "197400"),
+    answer(2, 1, "example", "15"),
+    answer(2, 2, "example", "12"),
+    answer(2, 1, "real", "10718"),
+    answer(2, 2, "real", "14652"),
+    answer(3, 1, "example", "157"),
+    answer(3, 2, "example", "70"),
+    answer(3, 1, "real", "7568"),
+    answer(3, 2, "real", "2780"),
+    answer(4, 1, "real", "538"),
+    answer(4, 2, "real", "792"),
+    answer(5, 1, "example", "CMZ"),
+    answer(5, 2, "example", "MCD"),
+    answer(5, 1, "real", "HBTMTBSDC"),
+    answer(5, 2, "real", "PQTJRSHWS"),
+    answer(6, 1, "real", "1640"),
+    answer(6, 2, "real", "3613"),
+    answer(7, 1, "example", "95437"),
+    answer(7, 2, "example", "24933642"),
+    answer(7, 1, "real", "1555642"),
+    answer(7, 2, "real", "5974547"),
+    answer(8, 1, "example", "21"),
+    answer(8, 2, "example", "8"),
+    answer(8, 1, "real", "1676"),
+    answer(8, 2, "real", "313200"),
+    answer(9, 1, "example", "13"),
+    answer(9, 2, "example", "1"),
+    answer(9, 2, "example2", "36"),
+    answer(9, 1, "real", "6067"),
+    answer(9, 2, "real", "2471"),
+    answer(10, 1, "example", "13140"),
+    answer(10, 2, "example", "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######....."),
+    answer(10, 1, "real", "14060"),
+    answer(10, 2, "real", "\
+###...##..###..#..#.####.#..#.####...##.
+#..#.#..#.#..#.#.#..#....#.#..#.......#.
+#..#.#..#.#..#.##...###..##...###.....#.
+###..####.###..#.#..#....#.#..#.......#.
+#....#..#.#....#.#..#....#.#..#....#..#.
+#....#..#.#....#..#.#....#..#.####..##.."),
+    answer(11, 1, "example", "10605"),
+    answer(11, 2, "example", "2713310158"),
+];
+
+// the recorded answer for a day and part on an input variant, if there is one
+pub fn expected(day: u32, part: u8, input: &str) -> Option<&'static str> {
+    ANSWERS
+        .iter()
+        .find(|answer| answer.day == day && answer.part == part && answer.input == input)
+        .map(|answer| answer.expected)
+}
@@ -0,0 +1,193 @@
+/*
+Running every day at once.
+
+Each day and part is a job, run on a pool of threads which take the
+next job from the list until there are none left. A job's answer is
+checked against the recorded answer for its input variant, and a
+solver which panics only fails its own job.
+*/
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use common::json;
+
+use crate::answers::expected;
+use crate::Day;
+
+pub struct Job {
+    pub day: &'static Day,
+    pub part: u8,
+    // input variant, such as "real" or "example"
+    pub variant: String,
+    // the input text, or why there is none
+    pub input: Result<String, String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail(&'static str),
+    // no recorded answer to check against
+    Unchecked,
+    // no answer at all, with the reason
+    Error(String),
+    // no input to run on, with the reason
+    Skipped(String),
+}
+
+pub struct Outcome {
+    pub day: u32,
+    pub part: u8,
+    pub variant: String,
+    pub answer: Option<String>,
+    pub elapsed: Duration,
+    pub verdict: Verdict,
+}
+
+impl Outcome {
+    // the outcome as a JSON record, such as
+    // {"day":2,"part":1,"variant":"example","answer":"15","elapsed":21,
+    //  "input_hash":"...","verdict":"pass","error":null}
+    // where the answer or input hash is null if there is none, and the
+    // error says why a job failed, was skipped, or gave no answer
+    pub fn to_json(&self, input_hash: Option<&str>) -> String {
+        let string_or_null = |s: Option<&str>| s.map_or("null".to_string(), json::string);
+        let (verdict, error) = match &self.verdict {
+            Verdict::Pass => ("pass", None),
+            Verdict::Fail(expected) => ("fail", Some(format!("expected {}", expected))),
+            Verdict::Unchecked => ("unchecked", None),
+            Verdict::Error(err) => ("error", Some(err.clone())),
+            Verdict::Skipped(reason) => ("skipped", Some(reason.clone())),
+        };
+        json::object(&[
+            ("day", self.day.to_string()),
+            ("part", self.part.to_string()),
+            ("variant", json::string(&self.variant)),
+            ("answer", string_or_null(self.answer.as_deref())),
+            ("elapsed", self.elapsed.as_micros().to_string()),
+            ("input_hash", string_or_null(input_hash)),
+            ("verdict", json::string(verdict)),
+            ("error", string_or_null(error.as_deref())),
+        ])
+    }
+}
+
+// run one job, timing the parse and solve together
+pub fn run_job(job: &Job) -> Outcome {
+    let input = match &job.input {
+        Ok(input) => input,
+        Err(err) => {
+            return Outcome {
+                day: job.day.day,
+                part: job.part,
+                variant: job.variant.clone(),
+                answer: None,
+                elapsed: Duration::ZERO,
+                verdict: Verdict::Skipped(err.clone()),
+            }
+        }
+    };
+    let start = Instant::now();
+    let result = match catch_unwind(AssertUnwindSafe(|| (job.day.solve)(input, job.part))) {
+        Ok(Ok(answer)) => Ok(answer),
+        Ok(Err(err)) => Err(err.to_string()),
+        Err(panic) => {
+            let message = panic
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            Err(format!("panicked: {}", message))
+        }
+    };
+    let elapsed = start.elapsed();
+    let (answer, verdict) = match result {
+        Ok(answer) => {
+            let verdict = match expected(job.day.day, job.part, &job.variant) {
+                Some(expected) if expected == answer => Verdict::Pass,
+                Some(expected) => Verdict::Fail(expected),
+                None => Verdict::Unchecked,
+            };
+            (Some(answer), verdict)
+        }
+        Err(err) => (None, Verdict::Error(err)),
+    };
+    Outcome {
+        day: job.day.day,
+        part: job.part,
+        variant: job.variant.clone(),
+        answer,
+        elapsed,
+        verdict,
+    }
+}
+
+// run all of the jobs on the given number of threads,
+// giving their outcomes in the same order as the jobs
+pub fn run_all(jobs: &[Job], threads: usize) -> Vec<Outcome> {
+    let next = AtomicUsize::new(0);
+    let outcomes = Mutex::new((0..jobs.len()).map(|_| None).collect::<Vec<Option<Outcome>>>());
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, jobs.len().max(1)) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(job) = jobs.get(i) else {
+                    break;
+                };
+                let outcome = run_job(job);
+                outcomes.lock().unwrap()[i] = Some(outcome);
+            });
+        }
+    });
+    outcomes.into_inner().unwrap().into_iter().map(|outcome| outcome.unwrap()).collect()
+}
+
+// the first line of an answer, cut short to fit in the table
+fn cut(answer: &str) -> String {
+    let line = answer.lines().next().unwrap_or("");
+    if line.len() < answer.len() || line.chars().count() > 24 {
+        format!("{}...", line.chars().take(21).collect::<String>())
+    } else {
+        line.to_string()
+    }
+}
+
+// the outcomes as a table, one row per job, with long answers
+// (such as day10's CRT) cut short
+pub fn table(outcomes: &[Outcome]) -> String {
+    let mut text = format!(
+        "{:>3} {:>4}  {:<8} {:<24} {:>12}  {}\n",
+        "day", "part", "input", "answer", "time", "check"
+    );
+    for outcome in outcomes {
+        let answer = outcome.answer.as_deref().map_or("-".to_string(), cut);
+        let check = match &outcome.verdict {
+            Verdict::Pass => "pass".to_string(),
+            Verdict::Fail(expected) => format!("FAIL (expected {})", cut(expected)),
+            Verdict::Unchecked => "-".to_string(),
+            Verdict::Error(err) => format!("ERROR {}", err.lines().next().unwrap_or("")),
+            Verdict::Skipped(reason) => format!("skipped, {}", reason),
+        };
+        text += &format!(
+            "{:>3} {:>4}  {:<8} {:<24} {:>9} us  {}\n",
+            outcome.day,
+            outcome.part,
+            outcome.variant,
+            answer,
+            outcome.elapsed.as_micros(),
+            check
+        );
+    }
+    text
+}
+
+// whether every job with an input gave an answer, and none a wrong one
+pub fn all_passed(outcomes: &[Outcome]) -> bool {
+    outcomes
+        .iter()
+        .all(|outcome| matches!(outcome.verdict, Verdict::Pass | Verdict::Unchecked | Verdict::Skipped(_)))
+}
@@ -0,0 +1,85 @@
+/*
+Benchmark baseline.
+
+The baseline records how long each phase of each day took, one
+tab-separated "day phase microseconds" line per measurement, so that
+later benchmark runs can flag any day whose runtime regressed.
+*/
+
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use common::bench::Timings;
+
+// differences smaller than this are timer noise, not regressions
+const NOISE_FLOOR_MICROS: u64 = 50;
+
+pub struct Measurement {
+    pub day: u32,
+    pub phase: &'static str,
+    pub micros: u64,
+}
+
+// split timings of a day into one measurement per phase
+pub fn measurements(day: u32, timings: &Timings) -> Vec<Measurement> {
+    [("parse", timings.parse), ("part1", timings.part1), ("part2", timings.part2)]
+        .into_iter()
+        .map(|(phase, duration)| Measurement {
+            day,
+            phase,
+            micros: duration.as_micros() as u64,
+        })
+        .collect()
+}
+
+// default location of the baseline, relative to the workspace root
+pub fn baseline_path() -> PathBuf {
+    let aoc = Path::new(env!("CARGO_MANIFEST_DIR"));
+    aoc.parent().unwrap_or(aoc).join("bench").join("baseline.tsv")
+}
+
+// microseconds of each phase, keyed by (day, phase)
+pub type Baseline = HashMap<(u32, String), u64>;
+
+// load a baseline
+pub fn load_baseline(path: &Path) -> io::Result<Baseline> {
+    let mut baseline = HashMap::new();
+    for line in std::fs::read_to_string(path)?.lines() {
+        let fields = line.split('\t').collect::<Vec<&str>>();
+        // skip the header and anything malformed
+        if let [day, phase, micros] = fields[..] {
+            if let (Ok(day), Ok(micros)) = (day.parse(), micros.parse()) {
+                baseline.insert((day, phase.to_string()), micros);
+            }
+        }
+    }
+    Ok(baseline)
+}
+
+// replace the baseline of the measured phases, keeping the others,
+// so that benchmarking one day leaves the rest of the baseline alone
+pub fn merge_baseline(baseline: &mut Baseline, measurements: &[Measurement]) {
+    for m in measurements {
+        baseline.insert((m.day, m.phase.to_string()), m.micros);
+    }
+}
+
+// save a baseline, ordered by day and phase
+pub fn save_baseline(path: &Path, baseline: &Baseline) -> io::Result<()> {
+    let mut rows = baseline.iter().collect::<Vec<_>>();
+    rows.sort();
+    let mut text = String::from("day\tphase\tmicros\n");
+    for ((day, phase), micros) in rows {
+        text.push_str(&format!("{}\t{}\t{}\n", day, phase, micros));
+    }
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, text)
+}
+
+// is the current time more than `threshold` percent slower than the baseline?
+pub fn is_regression(baseline: u64, current: u64, threshold: u64) -> bool {
+    current > baseline + baseline * threshold / 100 && current - baseline >= NOISE_FLOOR_MICROS
+}
@@ -0,0 +1,101 @@
+/*
+Frames of recorded simulations as PNG images or animated GIFs.
+
+Each character of a frame's picture becomes a square cell of colour:
+
+    . and space     background
+    #               lit pixel, visited square
+    0-9             tree heights, or rope knots, in shades of green
+    H               head of the rope
+    s               start
+    A-Z             crates, each letter its own colour, in [ ] frames
+    * >             highlights
+
+Frames of different sizes are drawn at the top left of the biggest.
+*/
+
+use common::simulate::{Frame, Recording};
+use raster::gif::Animation;
+use raster::{Image, Rgb};
+
+const BACKGROUND: u8 = 0;
+const DIGITS: u8 = 8;
+const LETTERS: u8 = DIGITS + 10;
+
+// at most this many frames are put in an animation unless asked for more
+pub const MAX_FRAMES: usize = 500;
+
+fn palette() -> Vec<Rgb> {
+    let mut palette = vec![
+        Rgb(16, 16, 35),
+        Rgb(255, 204, 0),
+        Rgb(80, 160, 255),
+        Rgb(230, 40, 40),
+        Rgb(255, 255, 255),
+        Rgb(230, 120, 40),
+        Rgb(110, 70, 30),
+        Rgb(128, 128, 128),
+    ];
+    for d in 0..10 {
+        palette.push(Rgb(20, 60 + 19 * d, 30));
+    }
+    // letters spread around the colour wheel
+    for i in 0..26 {
+        let hue = i as f64 / 26.0 * 6.0;
+        let x = (255.0 * (1.0 - (hue % 2.0 - 1.0).abs())) as u8;
+        palette.push(match hue as u32 {
+            0 => Rgb(255, x, 0),
+            1 => Rgb(x, 255, 0),
+            2 => Rgb(0, 255, x),
+            3 => Rgb(0, x, 255),
+            4 => Rgb(x, 0, 255),
+            _ => Rgb(255, 0, x),
+        });
+    }
+    palette
+}
+
+fn colour(&c: &char) -> u8 {
+    match c {
+        '.' | ' ' => BACKGROUND,
+        '#' => 1,
+        's' => 2,
+        'H' => 3,
+        '*' => 4,
+        '>' => 5,
+        '[' | ']' => 6,
+        '0'..='9' => DIGITS + (c as u8 - b'0'),
+        'A'..='Z' => LETTERS + (c as u8 - b'A'),
+        _ => 7,
+    }
+}
+
+// the frame's picture with each character as a scale x scale square
+pub fn image(frame: &Frame, scale: usize) -> Image {
+    Image::draw(&frame.picture, scale, &palette(), colour)
+}
+
+// the given steps of a recording as an animation,
+// showing each for the delay in hundredths of a second
+pub fn animation(recording: &Recording, steps: &[usize], scale: usize, delay: u16) -> Animation {
+    let images = steps
+        .iter()
+        .map(|&step| image(&recording.frame(step), scale))
+        .collect::<Vec<Image>>();
+    let width = images.iter().map(|image| image.width()).max().unwrap_or(0);
+    let height = images.iter().map(|image| image.height()).max().unwrap_or(0);
+    let mut animation = Animation::new();
+    for image in images {
+        animation.push(image.extend(width, height, BACKGROUND), delay);
+    }
+    animation
+}
+
+// every nth step, always ending with the last
+pub fn every(len: usize, n: usize) -> Vec<usize> {
+    let mut steps = (0..len).step_by(n.max(1)).collect::<Vec<usize>>();
+    if len > 0 && steps.last() != Some(&(len - 1)) {
+        steps.push(len - 1);
+    }
+    steps
+}
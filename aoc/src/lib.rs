@@ -0,0 +1,35 @@
+/*
+The runner's library, shared by the runner and the tests: the registry
+of the days (from the days crate), the input archive, and running,
+checking, timing and showing the days.
+*/
+
+use std::path::PathBuf;
+
+use store::Store;
+
+pub use days::{day, solver, Answerer, Checker, Day, Generate, Recorder, Solver, Timer, DAYS};
+
+pub mod answers;
+pub mod batch;
+pub mod bench;
+pub mod export;
+pub mod report;
+pub mod store;
+pub mod visualize;
+
+// the year all of the days are from
+pub const YEAR: u32 = 2022;
+
+// path to one of a day's input variants in the archive
+pub fn input_path(day: u32, variant: &str) -> PathBuf {
+    Store::from_env().path(YEAR, day, variant)
+}
+
+// the day's puzzle input, falling back to the example
+// for days where we do not have the real input
+pub fn default_input(day: u32) -> PathBuf {
+    Store::from_env()
+        .default_variant(YEAR, day)
+        .unwrap_or_else(|_| input_path(day, store::EXAMPLE))
+}
@@ -0,0 +1,670 @@
+/*
+Runner for all of the days.
+
+Usage:
+
+    aoc [OPTIONS] run <day> [--part 1|2] [--input PATH | --variant NAME] [--format text|json]
+    aoc [OPTIONS] run --all [--part 1|2] [--variant NAME] [--threads N] [--format text|json]
+    aoc [OPTIONS] bench [<day>] [--runs N] [--threshold PERCENT] [--save]
+    aoc [OPTIONS] inputs <day>
+    aoc generate <day> [--seed N] [--size N]
+    aoc [OPTIONS] show <day> [--part 1|2] [--input PATH | --variant NAME]
+    aoc [OPTIONS] export <day> [--part 1|2] [--input PATH | --variant NAME]
+                             [--step N | --every N] [--scale N] [--delay CS] <FILE.png|FILE.gif>
+    aoc [OPTIONS] query [--input PATH | --variant NAME] [QUERY]
+    aoc [OPTIONS] materialize [--input PATH | --variant NAME] --dir DIR | --tar FILE | --json | --tree
+    aoc [OPTIONS] stats [--input PATH | --variant NAME] [--buckets N] [--elf N] [--format text|json]
+
+Options, which may appear anywhere:
+
+    --quiet | --verbose | --trace
+    --archive DIR           where inputs are kept (default: inputs/)
+    --remote DIR|URL        where to fetch inputs missing from the archive
+
+If no part is given, both parts are run.
+Inputs are taken from the archive by variant name (see aoc/src/store.rs),
+such as "real" or "example". If no input is given, the real input is used,
+or the example when there is no real input.
+An input of "-" reads the puzzle from standard input.
+inputs lists the variants in the archive for a day, with their checksums.
+With --format json, each answer is printed as a JSON record
+(see aoc/src/report.rs) instead of as text; with run --all, every
+job is, with its verdict and any error (see aoc/src/batch.rs).
+run --all runs every day on a pool of threads (by default one per CPU),
+and prints a table of the answers and their times, checked against the
+recorded answers (see aoc/src/answers.rs). Days without the input are
+skipped; otherwise it fails if any answer is wrong or missing.
+
+By default only the answers are printed (--quiet, or -q).
+With --verbose (-v) the days also print summaries of what they did,
+and with --trace (-vv) a step-by-step account, all on standard error.
+
+The benchmark times parsing and each part of every day (or just the
+given day), comparing against the baseline in bench/baseline.tsv and
+flagging any phase that got slower by more than the threshold
+(default 25%). With --save, the results become the new baseline.
+Benchmarks are only meaningful in a release build.
+
+generate prints a random puzzle input for the day. The same seed
+(default 0) always gives the same input; what the size means depends
+on the day (elves, rounds, moves, grid rows, ...).
+
+show plays back one part (default 1) of a day which simulates something,
+step by step in the terminal (see aoc/src/visualize.rs for the keys).
+Days 5, 8, 9, 10 and 11 can be shown.
+
+export draws the same frames as images (see aoc/src/export.rs), with
+each character as a square of --scale pixels (default 4). A .png file
+gets a single step, the last unless --step is given. A .gif file gets
+an animation of every --every'th step (by default enough to make at
+most 500 frames), each shown for --delay hundredths of a second
+(default 5), or just the one step given by --step.
+
+query answers questions about the filesystem of day 7, such as
+`du /a --depth 1`, `find --name *.txt --min 1000` or `top 5`
+(see day7/src/query.rs). Without a query it reads them one per line
+from standard input until the end, or `quit`.
+
+materialize makes the same filesystem real (see day7/src/materialize.rs):
+as directories and sparse files under DIR, as a tar archive (- for
+standard output), or printed as JSON or as a tree.
+
+stats describes how the calories of day 1 are spread among the elves
+(see day1/src/stats.rs), with a histogram of --buckets buckets
+(default 10), and the rank of the elf given by --elf.
+*/
+
+use std::io::{self, BufRead, Write};
+use std::process::exit;
+use std::thread;
+use std::time::Instant;
+
+use aoc::batch::{self, Job, Verdict};
+use aoc::bench::{baseline_path, is_regression, load_baseline, measurements, merge_baseline, save_baseline};
+use aoc::report::{input_hash, stream_hash, Record};
+use aoc::store::{Remote, Store};
+use aoc::export::{self, MAX_FRAMES};
+use aoc::{visualize, DAYS, YEAR};
+use common::log::{self, Level};
+use common::{LoadError, Solution, Source};
+use day7::materialize;
+use day7::query::{self, Query};
+
+const USAGE: &str = "Usage: aoc [OPTIONS] run <day> [--part 1|2] [--input PATH | --variant NAME] [--format text|json]
+       aoc [OPTIONS] run --all [--part 1|2] [--variant NAME] [--threads N] [--format text|json]
+       aoc [OPTIONS] bench [<day>] [--runs N] [--threshold PERCENT] [--save]
+       aoc [OPTIONS] inputs <day>
+       aoc generate <day> [--seed N] [--size N]
+       aoc [OPTIONS] show <day> [--part 1|2] [--input PATH | --variant NAME]
+       aoc [OPTIONS] export <day> [--part 1|2] [--input PATH | --variant NAME]
+                             [--step N | --every N] [--scale N] [--delay CS] <FILE.png|FILE.gif>
+       aoc [OPTIONS] query [--input PATH | --variant NAME] [QUERY]
+       aoc [OPTIONS] materialize [--input PATH | --variant NAME] --dir DIR | --tar FILE | --json | --tree
+       aoc [OPTIONS] stats [--input PATH | --variant NAME] [--buckets N] [--elf N] [--format text|json]
+Options: -q|-v|-vv, --archive DIR, --remote DIR|URL";
+
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    exit(2);
+}
+
+// print an error and exit
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    exit(1);
+}
+
+fn main() {
+    // options may appear anywhere
+    let mut args: Vec<String> = Vec::new();
+    let mut store = Store::from_env();
+    let mut all_args = std::env::args().skip(1);
+    while let Some(arg) = all_args.next() {
+        match arg.as_str() {
+            "-q" | "--quiet" => log::set_level(Level::Quiet),
+            "-v" | "--verbose" => log::set_level(Level::Verbose),
+            "-vv" | "--trace" => log::set_level(Level::Trace),
+            "--archive" => {
+                let dir = all_args.next().unwrap_or_else(|| usage());
+                store = store.with_dir(dir);
+            }
+            "--remote" => {
+                let remote = all_args.next().unwrap_or_else(|| usage());
+                store = store.with_remote(Remote::from_arg(&remote));
+            }
+            _ => args.push(arg),
+        }
+    }
+    match args.first().map(|s| s.as_str()) {
+        Some("run") => run(&store, &args[1..]),
+        Some("bench") => bench(&store, &args[1..]),
+        Some("inputs") => inputs(&store, &args[1..]),
+        Some("generate") => generate(&args[1..]),
+        Some("show") => show(&store, &args[1..]),
+        Some("export") => export(&store, &args[1..]),
+        Some("query") => query(&store, &args[1..]),
+        Some("materialize") => materialize(&store, &args[1..]),
+        Some("stats") => stats(&store, &args[1..]),
+        _ => usage(),
+    }
+}
+
+// look up a day given on the command line
+fn parse_day(arg: &str) -> &'static aoc::Day {
+    let day = arg.parse::<u32>().unwrap_or_else(|_| usage());
+    aoc::day(day).unwrap_or_else(|| fail(&format!("day {} is not available", day)))
+}
+
+// read a day's input, exiting on failure
+fn read_input(source: &Source) -> String {
+    source.read().unwrap_or_else(|err| fail(&err.to_string()))
+}
+
+// the day's default input, exiting if there is none
+fn default_input(store: &Store, day: u32) -> Source {
+    Source::File(store.default_variant(YEAR, day).unwrap_or_else(|err| fail(&err.to_string())))
+}
+
+// the input given by --input or --variant, or else the default
+fn choose_input(store: &Store, day: u32, input: Option<Source>, variant: Option<&String>) -> Source {
+    match (input, variant) {
+        (Some(_), Some(_)) => usage(),
+        (Some(input), None) => input,
+        (None, Some(variant)) => {
+            Source::File(store.resolve(YEAR, day, variant).unwrap_or_else(|err| fail(&err.to_string())))
+        }
+        (None, None) => default_input(store, day),
+    }
+}
+
+fn run(store: &Store, args: &[String]) {
+    let mut day = None;
+    let mut all = false;
+    let mut threads = None;
+    let mut parts = vec![1, 2];
+    let mut input = None;
+    let mut variant = None;
+    let mut json = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--threads" => {
+                threads = Some(args.next().and_then(|s| s.parse().ok()).filter(|&n| n > 0).unwrap_or_else(|| usage()));
+            }
+            "--part" => {
+                parts = match args.next().map(|s| s.as_str()) {
+                    Some("1") => vec![1],
+                    Some("2") => vec![2],
+                    _ => usage(),
+                };
+            }
+            "--input" => {
+                input = Some(Source::from_arg(args.next().unwrap_or_else(|| usage())));
+            }
+            "--variant" => variant = Some(args.next().unwrap_or_else(|| usage())),
+            "--format" => {
+                json = match args.next().map(|s| s.as_str()) {
+                    Some("text") => false,
+                    Some("json") => true,
+                    _ => usage(),
+                };
+            }
+            _ if day.is_none() => day = Some(parse_day(arg)),
+            _ => usage(),
+        }
+    }
+    if all {
+        if day.is_some() || input.is_some() {
+            usage();
+        }
+        let threads = threads.unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()));
+        return run_all(store, &parts, variant.map(|s| s.as_str()), threads, json);
+    }
+    let day = day.unwrap_or_else(|| usage());
+    if threads.is_some() {
+        usage();
+    }
+
+    let mut source = choose_input(store, day.day, input, variant);
+    // standard input can only be read once, so it is kept for the hash
+    if json && matches!(source, Source::Stdin) {
+        source = Source::Text(read_input(&source));
+    }
+    // loaded once for both parts, and streamed by the days which can,
+    // so that day 7 can replay logs bigger than memory
+    let answers = (day.answer)(&source, &parts).unwrap_or_else(|err| fail(&err.to_string()));
+    let hash = if json {
+        source
+            .open()
+            .and_then(|reader| stream_hash(reader).map_err(|err| LoadError::Io(source.name(), err)))
+            .unwrap_or_else(|err| fail(&err.to_string()))
+    } else {
+        String::new()
+    };
+
+    let mut failed = false;
+    for (&part, (answer, elapsed)) in parts.iter().zip(answers) {
+        // valid input can still have no answer, which fails the run
+        // once the other part has had its turn
+        let answer = match answer {
+            Ok(answer) => answer,
+            Err(err) => {
+                eprintln!("day {} part {}: {}", day.day, part, err);
+                failed = true;
+                continue;
+            }
+        };
+        if json {
+            let record = Record {
+                day: day.day,
+                part,
+                answer,
+                elapsed,
+                input_hash: hash.clone(),
+            };
+            println!("{}", record.to_json());
+        } else {
+            println!("# Day {} part {}", day.day, part);
+            println!("{}", answer);
+        }
+    }
+    if failed {
+        exit(1);
+    }
+}
+
+// run every day, either on the given variant or on each day's default
+fn run_all(store: &Store, parts: &[u8], variant: Option<&str>, threads: usize, json: bool) {
+    let mut jobs = Vec::new();
+    for day in DAYS {
+        let path = match variant {
+            Some(variant) => store.resolve(YEAR, day.day, variant),
+            None => store.default_variant(YEAR, day.day),
+        };
+        let name = match (&path, variant) {
+            (Ok(path), _) => path.file_name().map_or(String::new(), |name| name.to_string_lossy().into_owned()),
+            (Err(_), Some(variant)) => variant.to_string(),
+            (Err(_), None) => "-".to_string(),
+        };
+        let input = path
+            .and_then(std::fs::read_to_string)
+            .map_err(|err| err.to_string());
+        for &part in parts {
+            jobs.push(Job {
+                day,
+                part,
+                variant: name.clone(),
+                input: input.clone(),
+            });
+        }
+    }
+
+    let start = Instant::now();
+    let outcomes = batch::run_all(&jobs, threads);
+    let elapsed = start.elapsed();
+
+    if json {
+        // one record per job, whether or not it gave an answer
+        for (job, outcome) in jobs.iter().zip(&outcomes) {
+            let hash = job.input.as_deref().ok().map(input_hash);
+            println!("{}", outcome.to_json(hash.as_deref()));
+        }
+    } else {
+        print!("{}", batch::table(&outcomes));
+        let count = |f: fn(&Verdict) -> bool| outcomes.iter().filter(|outcome| f(&outcome.verdict)).count();
+        println!(
+            "{} passed, {} failed, {} errors, {} unchecked, {} skipped, in {} ms on {} thread(s)",
+            count(|v| *v == Verdict::Pass),
+            count(|v| matches!(v, Verdict::Fail(_))),
+            count(|v| matches!(v, Verdict::Error(_))),
+            count(|v| *v == Verdict::Unchecked),
+            count(|v| matches!(v, Verdict::Skipped(_))),
+            elapsed.as_millis(),
+            threads
+        );
+    }
+    if !batch::all_passed(&outcomes) {
+        exit(1);
+    }
+}
+
+fn bench(store: &Store, args: &[String]) {
+    let mut days = DAYS.iter().collect::<Vec<_>>();
+    let mut runs = 5;
+    let mut threshold = 25;
+    let mut save = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runs" => runs = args.next().and_then(|s| s.parse().ok()).unwrap_or_else(|| usage()),
+            "--threshold" => threshold = args.next().and_then(|s| s.parse().ok()).unwrap_or_else(|| usage()),
+            "--save" => save = true,
+            _ => days = vec![parse_day(arg)],
+        }
+    }
+
+    let path = baseline_path();
+    let mut baseline = load_baseline(&path).unwrap_or_default();
+    let mut results = Vec::new();
+    for day in days {
+        let source = default_input(store, day.day);
+        let input = read_input(&source);
+        let timings = (day.time)(&input, runs)
+            .unwrap_or_else(|err| fail(&err.in_file(&source.name()).to_string()));
+        results.extend(measurements(day.day, &timings));
+    }
+
+    let mut regressions = 0;
+    println!("{:>3}  {:<6} {:>12} {:>12} {:>8}", "day", "phase", "time", "baseline", "change");
+    for m in &results {
+        match baseline.get(&(m.day, m.phase.to_string())) {
+            Some(&base) => {
+                let change = (m.micros as f64 / base.max(1) as f64 - 1.0) * 100.0;
+                let flag = if is_regression(base, m.micros, threshold) {
+                    regressions += 1;
+                    "  REGRESSION"
+                } else {
+                    ""
+                };
+                println!("{:>3}  {:<6} {:>9} us {:>9} us {:>+7.0}%{}", m.day, m.phase, m.micros, base, change, flag);
+            }
+            None => println!("{:>3}  {:<6} {:>9} us {:>12} {:>8}", m.day, m.phase, m.micros, "-", "-"),
+        }
+    }
+
+    if save {
+        merge_baseline(&mut baseline, &results);
+        save_baseline(&path, &baseline).unwrap_or_else(|err| fail(&format!("cannot save {}: {}", path.display(), err)));
+        println!("saved baseline to {}", path.display());
+    } else if regressions > 0 {
+        fail(&format!("{} phase(s) regressed by more than {}%", regressions, threshold));
+    }
+}
+
+fn inputs(store: &Store, args: &[String]) {
+    let day = match args {
+        [day] => parse_day(day),
+        _ => usage(),
+    };
+    let variants = store
+        .variants(YEAR, day.day)
+        .unwrap_or_else(|err| fail(&format!("cannot list inputs: {}", err)));
+    for variant in variants {
+        println!("{:<10} {}  {}", variant.name, variant.checksum, variant.path.display());
+    }
+}
+
+fn generate(args: &[String]) {
+    let mut day = None;
+    let mut seed = 0;
+    let mut size = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => seed = args.next().and_then(|s| s.parse().ok()).unwrap_or_else(|| usage()),
+            "--size" => size = Some(args.next().and_then(|s| s.parse().ok()).unwrap_or_else(|| usage())),
+            _ if day.is_none() => day = Some(parse_day(arg)),
+            _ => usage(),
+        }
+    }
+    let day = day.unwrap_or_else(|| usage());
+    print!("{}", (day.generate)(seed, size.unwrap_or(day.default_size)));
+}
+
+fn show(store: &Store, args: &[String]) {
+    let mut day = None;
+    let mut part = 1;
+    let mut input = None;
+    let mut variant = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                part = match args.next().map(|s| s.as_str()) {
+                    Some("1") => 1,
+                    Some("2") => 2,
+                    _ => usage(),
+                };
+            }
+            "--input" => {
+                input = Some(Source::from_arg(args.next().unwrap_or_else(|| usage())));
+            }
+            "--variant" => variant = Some(args.next().unwrap_or_else(|| usage())),
+            _ if day.is_none() => day = Some(parse_day(arg)),
+            _ => usage(),
+        }
+    }
+    let day = day.unwrap_or_else(|| usage());
+    let record = day
+        .record
+        .unwrap_or_else(|| fail(&format!("day {} has nothing to show", day.day)));
+
+    let source = choose_input(store, day.day, input, variant);
+    let input = read_input(&source);
+    let recording = record(&input, part).unwrap_or_else(|err| fail(&err.in_file(&source.name()).to_string()));
+    visualize::run(&recording).unwrap_or_else(|err| fail(&format!("cannot show day {}: {}", day.day, err)));
+}
+
+fn export(store: &Store, args: &[String]) {
+    let mut day = None;
+    let mut part = 1;
+    let mut input = None;
+    let mut variant = None;
+    let mut step = None;
+    let mut every = None;
+    let mut scale = 4;
+    let mut delay = 5;
+    let mut output = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                part = match args.next().map(|s| s.as_str()) {
+                    Some("1") => 1,
+                    Some("2") => 2,
+                    _ => usage(),
+                };
+            }
+            "--input" => {
+                input = Some(Source::from_arg(args.next().unwrap_or_else(|| usage())));
+            }
+            "--variant" => variant = Some(args.next().unwrap_or_else(|| usage())),
+            "--step" => step = Some(args.next().and_then(|s| s.parse().ok()).unwrap_or_else(|| usage())),
+            "--every" => every = Some(args.next().and_then(|s| s.parse().ok()).unwrap_or_else(|| usage())),
+            "--scale" => scale = args.next().and_then(|s| s.parse().ok()).filter(|&s| s > 0).unwrap_or_else(|| usage()),
+            "--delay" => delay = args.next().and_then(|s| s.parse().ok()).unwrap_or_else(|| usage()),
+            _ if day.is_none() => day = Some(parse_day(arg)),
+            _ if output.is_none() => output = Some(arg.clone()),
+            _ => usage(),
+        }
+    }
+    let (day, output) = match (day, output) {
+        (Some(day), Some(output)) => (day, output),
+        _ => usage(),
+    };
+    if step.is_some() && every.is_some() {
+        usage();
+    }
+    let record = day
+        .record
+        .unwrap_or_else(|| fail(&format!("day {} has nothing to export", day.day)));
+
+    let source = choose_input(store, day.day, input, variant);
+    let input = read_input(&source);
+    let recording = record(&input, part).unwrap_or_else(|err| fail(&err.in_file(&source.name()).to_string()));
+    if recording.is_empty() {
+        fail(&format!("day {} part {} recorded nothing", day.day, part));
+    }
+    let last = recording.len() - 1;
+    if step.is_some_and(|step| step > last) {
+        fail(&format!("there are only steps 0 to {}", last));
+    }
+
+    let bytes = if output.ends_with(".png") {
+        if every.is_some() {
+            usage();
+        }
+        raster::png::encode(&export::image(&recording.frame(step.unwrap_or(last)), scale))
+    } else if output.ends_with(".gif") {
+        let steps = match step {
+            Some(step) => vec![step],
+            None => export::every(recording.len(), every.unwrap_or(recording.len().div_ceil(MAX_FRAMES))),
+        };
+        export::animation(&recording, &steps, scale, delay).encode()
+    } else {
+        fail("the output file must end in .png or .gif");
+    };
+    std::fs::write(&output, bytes).unwrap_or_else(|err| fail(&format!("cannot write {}: {}", output, err)));
+}
+
+fn query(store: &Store, args: &[String]) {
+    let mut input = None;
+    let mut variant = None;
+    let mut words = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                input = Some(Source::from_arg(args.next().unwrap_or_else(|| usage())));
+            }
+            "--variant" => variant = Some(args.next().unwrap_or_else(|| usage())),
+            // everything else is the query, options and all
+            _ => words.push(arg.as_str()),
+        }
+    }
+    let source = choose_input(store, 7, input, variant);
+    // the log is streamed, so it may be far bigger than memory
+    let fs = day7::Day7::load(&source).unwrap_or_else(|err| fail(&err.to_string()));
+
+    if !words.is_empty() {
+        if let Err(err) = answer(&fs, &words.join(" ")) {
+            fail(&err);
+        }
+        return;
+    }
+    let stdin = io::stdin();
+    loop {
+        print!("> ");
+        io::stdout().flush().unwrap_or_else(|err| fail(&err.to_string()));
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).unwrap_or_else(|err| fail(&err.to_string())) == 0 {
+            println!();
+            break;
+        }
+        match line.trim() {
+            "" => continue,
+            "quit" | "exit" => break,
+            text => {
+                if let Err(err) = answer(&fs, text) {
+                    eprintln!("{}", err);
+                }
+            }
+        }
+    }
+}
+
+// print the answer to a query
+fn answer(fs: &day7::FileSystem, text: &str) -> Result<(), String> {
+    let query = Query::parse(text).map_err(|err| err.to_string())?;
+    print!("{}", query::format(fs, &query.run(fs)?));
+    Ok(())
+}
+
+enum Target {
+    Dir(String),
+    Tar(String),
+    Json,
+    Tree,
+}
+
+fn materialize(store: &Store, args: &[String]) {
+    let mut input = None;
+    let mut variant = None;
+    let mut target = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let next = match arg.as_str() {
+            "--input" => {
+                input = Some(Source::from_arg(args.next().unwrap_or_else(|| usage())));
+                continue;
+            }
+            "--variant" => {
+                variant = Some(args.next().unwrap_or_else(|| usage()));
+                continue;
+            }
+            "--dir" => Target::Dir(args.next().unwrap_or_else(|| usage()).clone()),
+            "--tar" => Target::Tar(args.next().unwrap_or_else(|| usage()).clone()),
+            "--json" => Target::Json,
+            "--tree" => Target::Tree,
+            _ => usage(),
+        };
+        if target.replace(next).is_some() {
+            usage();
+        }
+    }
+    let target = target.unwrap_or_else(|| usage());
+    let source = choose_input(store, 7, input, variant);
+    let fs = day7::Day7::load(&source).unwrap_or_else(|err| fail(&err.to_string()));
+
+    let result = match target {
+        Target::Dir(dir) => materialize::write_dir(&fs, std::path::Path::new(&dir)),
+        Target::Tar(file) if file == "-" => materialize::write_tar(&fs, &mut io::stdout().lock()),
+        Target::Tar(file) => std::fs::File::create(&file)
+            .map(io::BufWriter::new)
+            .and_then(|mut out| materialize::write_tar(&fs, &mut out).and_then(|_| out.flush())),
+        Target::Json => {
+            println!("{}", materialize::json(&fs));
+            Ok(())
+        }
+        Target::Tree => {
+            print!("{}", materialize::tree(&fs));
+            Ok(())
+        }
+    };
+    result.unwrap_or_else(|err| fail(&format!("cannot materialize: {}", err)));
+}
+
+fn stats(store: &Store, args: &[String]) {
+    let mut input = None;
+    let mut variant = None;
+    let mut buckets = 10;
+    let mut elf = None;
+    let mut json = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                input = Some(Source::from_arg(args.next().unwrap_or_else(|| usage())));
+            }
+            "--variant" => variant = Some(args.next().unwrap_or_else(|| usage())),
+            "--buckets" => buckets = args.next().and_then(|s| s.parse().ok()).filter(|&n| n > 0).unwrap_or_else(|| usage()),
+            "--elf" => elf = Some(args.next().and_then(|s| s.parse().ok()).unwrap_or_else(|| usage())),
+            "--format" => {
+                json = match args.next().map(|s| s.as_str()) {
+                    Some("text") => false,
+                    Some("json") => true,
+                    _ => usage(),
+                };
+            }
+            _ => usage(),
+        }
+    }
+    let source = choose_input(store, 1, input, variant);
+    let elves = day1::Day1::load(&source).unwrap_or_else(|err| fail(&err.to_string()));
+    let stats = day1::Stats::new(&elves, buckets).unwrap_or_else(|| fail("there are no elves"));
+    let found = elf.map(|elf| {
+        stats
+            .elf(elf)
+            .zip(stats.rank(elf))
+            .unwrap_or_else(|| fail(&format!("there is no elf {}, only 1 to {}", elf, stats.elves())))
+    });
+    if json {
+        println!("{}", stats.to_json(elf));
+        return;
+    }
+    print!("{}", stats.table());
+    if let (Some(elf), Some(((total, items), rank))) = (elf, found) {
+        println!("\nelf {} carries {} calories in {} items, rank {} of {}", elf, total, items, rank, stats.elves());
+    }
+}
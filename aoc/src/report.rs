@@ -0,0 +1,65 @@
+/*
+Machine-readable answers, one JSON object per line:
+
+    {"day":6,"part":1,"answer":"1640","elapsed":52,"input_hash":"270949040e451f0b"}
+
+`elapsed` is the time taken to parse the input and solve the part,
+in microseconds. `input_hash` is the 64-bit FNV-1a hash of the input
+text in hex, so that results can be matched up with the input used.
+*/
+
+use std::io::{self, BufRead};
+use std::time::Duration;
+
+use common::json;
+
+pub struct Record {
+    pub day: u32,
+    pub part: u8,
+    pub answer: String,
+    pub elapsed: Duration,
+    pub input_hash: String,
+}
+
+impl Record {
+    pub fn to_json(&self) -> String {
+        json::object(&[
+            ("day", self.day.to_string()),
+            ("part", self.part.to_string()),
+            ("answer", json::string(&self.answer)),
+            ("elapsed", self.elapsed.as_micros().to_string()),
+            ("input_hash", json::string(&self.input_hash)),
+        ])
+    }
+}
+
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+
+fn fnv(mut hash: u64, bytes: &[u8]) -> u64 {
+    for &byte in bytes {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+// 64-bit FNV-1a hash of the input, which is stable across builds and platforms
+pub fn input_hash(input: &str) -> String {
+    format!("{:016x}", fnv(FNV_OFFSET, input.as_bytes()))
+}
+
+// the same hash of an input read as a stream, for inputs too big to hold
+pub fn stream_hash(mut reader: impl BufRead) -> io::Result<String> {
+    let mut hash = FNV_OFFSET;
+    loop {
+        let len = {
+            let bytes = reader.fill_buf()?;
+            hash = fnv(hash, bytes);
+            bytes.len()
+        };
+        if len == 0 {
+            return Ok(format!("{:016x}", hash));
+        }
+        reader.consume(len);
+    }
+}
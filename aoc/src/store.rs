@@ -0,0 +1,231 @@
+/*
+Archive of puzzle inputs.
+
+Inputs are kept in an archive directory, one file per variant:
+
+    <archive>/<year>/day<day>/<variant>
+
+where the variant is "real" for the real puzzle input, and "example",
+"example2" and so on for the examples given in the puzzle text.
+The archive defaults to the inputs directory of the workspace, or
+$AOC_ARCHIVE if it is set.
+
+Variants missing from the archive can be fetched from a remote, which
+is either another directory laid out the same way, or a web server
+laid out like the puzzle site:
+
+    <url>/<year>/day/<day>/input        (the real input)
+    <url>/<year>/day/<day>/<variant>    (anything else)
+
+Fetched inputs are saved to the archive. $AOC_REMOTE sets the remote,
+and $AOC_SESSION the session cookie sent along with requests.
+Only plain http:// is supported, which is enough for a local stand-in
+for the puzzle site.
+*/
+
+use std::fs;
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+use std::path::{Path, PathBuf};
+
+use crate::report::input_hash;
+
+pub const REAL: &str = "real";
+pub const EXAMPLE: &str = "example";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Remote {
+    Dir(PathBuf),
+    Http(String),
+}
+
+impl Remote {
+    // a remote given as a URL or a directory
+    pub fn from_arg(arg: &str) -> Remote {
+        if arg.contains("://") {
+            Remote::Http(arg.trim_end_matches('/').to_string())
+        } else {
+            Remote::Dir(PathBuf::from(arg))
+        }
+    }
+}
+
+// one input in the archive
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Variant {
+    pub name: String,
+    pub path: PathBuf,
+    // FNV-1a hash of the contents, as in the JSON reports
+    pub checksum: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct Store {
+    dir: PathBuf,
+    remote: Option<Remote>,
+    session: Option<String>,
+}
+
+impl Store {
+    pub fn new(dir: impl Into<PathBuf>) -> Store {
+        Store {
+            dir: dir.into(),
+            remote: None,
+            session: None,
+        }
+    }
+
+    // the store configured by the environment
+    pub fn from_env() -> Store {
+        let dir = std::env::var_os("AOC_ARCHIVE").map(PathBuf::from).unwrap_or_else(|| {
+            let aoc = Path::new(env!("CARGO_MANIFEST_DIR"));
+            aoc.parent().unwrap_or(aoc).join("inputs")
+        });
+        let mut store = Store::new(dir);
+        store.remote = std::env::var("AOC_REMOTE").ok().map(|remote| Remote::from_arg(&remote));
+        store.session = std::env::var("AOC_SESSION").ok();
+        store
+    }
+
+    pub fn with_dir(mut self, dir: impl Into<PathBuf>) -> Store {
+        self.dir = dir.into();
+        self
+    }
+
+    pub fn with_remote(mut self, remote: Remote) -> Store {
+        self.remote = Some(remote);
+        self
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    // where a variant is (or would be) kept in the archive
+    pub fn path(&self, year: u32, day: u32, variant: &str) -> PathBuf {
+        self.dir.join(year.to_string()).join(format!("day{}", day)).join(variant)
+    }
+
+    // the variants in the archive for a day, real first and then by name
+    pub fn variants(&self, year: u32, day: u32) -> io::Result<Vec<Variant>> {
+        let dir = self.path(year, day, "");
+        let mut names = Vec::new();
+        match fs::read_dir(&dir) {
+            Ok(entries) => {
+                for entry in entries {
+                    let entry = entry?;
+                    if entry.file_type()?.is_file() {
+                        if let Ok(name) = entry.file_name().into_string() {
+                            names.push(name);
+                        }
+                    }
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+        names.sort_by_key(|name| (name != REAL, name.clone()));
+        names
+            .into_iter()
+            .map(|name| {
+                let path = self.path(year, day, &name);
+                let checksum = input_hash(&fs::read_to_string(&path)?);
+                Ok(Variant { name, path, checksum })
+            })
+            .collect()
+    }
+
+    // path to a variant in the archive, fetching it from the remote first
+    // if it is not there yet
+    pub fn resolve(&self, year: u32, day: u32, variant: &str) -> io::Result<PathBuf> {
+        check_name(variant)?;
+        let path = self.path(year, day, variant);
+        if path.exists() {
+            return Ok(path);
+        }
+        let text = match &self.remote {
+            Some(remote) => self.fetch(remote, year, day, variant)?,
+            None => {
+                let message = format!("no {} input for {} day {} in {}", variant, year, day, self.dir.display());
+                return Err(io::Error::new(io::ErrorKind::NotFound, message));
+            }
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, text)?;
+        Ok(path)
+    }
+
+    // contents of a variant, fetching it if need be
+    pub fn read(&self, year: u32, day: u32, variant: &str) -> io::Result<String> {
+        fs::read_to_string(self.resolve(year, day, variant)?)
+    }
+
+    // the real input if there is one, or else the example.
+    // Only a missing real input falls back: failing to fetch or save
+    // it is an error of its own.
+    pub fn default_variant(&self, year: u32, day: u32) -> io::Result<PathBuf> {
+        match self.resolve(year, day, REAL) {
+            Err(err) if err.kind() == io::ErrorKind::NotFound => self.resolve(year, day, EXAMPLE),
+            result => result,
+        }
+    }
+
+    fn fetch(&self, remote: &Remote, year: u32, day: u32, variant: &str) -> io::Result<String> {
+        match remote {
+            Remote::Dir(dir) => Store::new(dir).read(year, day, variant),
+            Remote::Http(url) => {
+                let file = if variant == REAL { "input" } else { variant };
+                http_get(&format!("{}/{}/day/{}/{}", url, year, day, file), self.session.as_deref())
+            }
+        }
+    }
+}
+
+// variant names are file names, so must not wander out of the archive
+fn check_name(variant: &str) -> io::Result<()> {
+    let valid = !variant.is_empty()
+        && !variant.starts_with('.')
+        && variant.chars().all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c));
+    if !valid {
+        let message = format!("invalid input variant {:?}", variant);
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, message));
+    }
+    Ok(())
+}
+
+// fetch a page with a bare-bones HTTP/1.0 GET
+fn http_get(url: &str, session: Option<&str>) -> io::Result<String> {
+    let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidInput, message);
+    let rest = url
+        .strip_prefix("http://")
+        .ok_or_else(|| invalid(format!("only http:// remotes are supported, not {}", url)))?;
+    let (host, path) = match rest.find('/') {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, "/"),
+    };
+    let address = if host.contains(':') { host.to_string() } else { format!("{}:80", host) };
+
+    let mut stream = TcpStream::connect(address)?;
+    let mut request = format!("GET {} HTTP/1.0\r\nHost: {}\r\nUser-Agent: aoc-runner\r\n", path, host);
+    if let Some(session) = session {
+        request += &format!("Cookie: session={}\r\n", session);
+    }
+    request += "\r\n";
+    stream.write_all(request.as_bytes())?;
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+
+    let (head, body) = response
+        .split_once("\r\n\r\n")
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, format!("malformed response from {}", url)))?;
+    let status = head.lines().next().unwrap_or("");
+    let code = status.split_whitespace().nth(1);
+    if code != Some("200") {
+        // only a page which is not there means the input does not exist
+        let kind = if code == Some("404") { io::ErrorKind::NotFound } else { io::ErrorKind::Other };
+        return Err(io::Error::new(kind, format!("{} returned {}", url, status)));
+    }
+    Ok(body.to_string())
+}
@@ -0,0 +1,267 @@
+/*
+Terminal visualizer for the days which record their simulations.
+
+The recording is played back one frame per step, showing the caption
+and as much of the picture as fits on the screen, scrolled to keep the
+frame's focus in view.
+
+Keys:
+
+    space           play / pause
+    right, left     step forward / back (pauses)
+    r               play backwards (rewind) / forwards
+    home, end       first / last step
+    + -             faster / slower
+    q, esc          quit
+
+The player itself knows nothing of the terminal, so it can be tested.
+*/
+
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use common::simulate::{Frame, Recording};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::{cursor, execute, queue, style, terminal};
+
+// playback speeds, in steps per second
+const SPEEDS: [usize; 13] = [1, 2, 5, 10, 20, 50, 100, 200, 500, 1000, 2000, 5000, 10000];
+
+// the screen is never redrawn more often than this while playing
+const MIN_INTERVAL: Duration = Duration::from_millis(40);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command {
+    PlayPause,
+    Forward,
+    Back,
+    Reverse,
+    First,
+    Last,
+    Faster,
+    Slower,
+    Quit,
+}
+
+// what a key does, if anything
+pub fn command(key: KeyEvent) -> Option<Command> {
+    if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+        return Some(Command::Quit);
+    }
+    match key.code {
+        KeyCode::Char(' ') => Some(Command::PlayPause),
+        KeyCode::Right | KeyCode::Char('l') => Some(Command::Forward),
+        KeyCode::Left | KeyCode::Char('h') => Some(Command::Back),
+        KeyCode::Char('r') => Some(Command::Reverse),
+        KeyCode::Home | KeyCode::Char('g') => Some(Command::First),
+        KeyCode::End | KeyCode::Char('G') => Some(Command::Last),
+        KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Up => Some(Command::Faster),
+        KeyCode::Char('-') | KeyCode::Down => Some(Command::Slower),
+        KeyCode::Char('q') | KeyCode::Esc => Some(Command::Quit),
+        _ => None,
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Player {
+    // number of steps recorded
+    pub len: usize,
+    // the step being shown
+    pub step: usize,
+    pub playing: bool,
+    pub reverse: bool,
+    // index into SPEEDS
+    speed: usize,
+    quit: bool,
+}
+
+impl Player {
+    pub fn new(len: usize) -> Player {
+        Player {
+            len,
+            step: 0,
+            playing: false,
+            reverse: false,
+            speed: 3,
+            quit: false,
+        }
+    }
+
+    pub fn quit(&self) -> bool {
+        self.quit
+    }
+
+    // steps per second when playing
+    pub fn speed(&self) -> usize {
+        SPEEDS[self.speed]
+    }
+
+    fn last(&self) -> usize {
+        self.len.saturating_sub(1)
+    }
+
+    pub fn apply(&mut self, command: Command) {
+        match command {
+            Command::PlayPause => {
+                // playing from the end starts again from the other end
+                if !self.playing && self.at_end() {
+                    self.step = if self.reverse { self.last() } else { 0 };
+                }
+                self.playing = !self.playing;
+            }
+            Command::Forward => {
+                self.playing = false;
+                self.step = (self.step + 1).min(self.last());
+            }
+            Command::Back => {
+                self.playing = false;
+                self.step = self.step.saturating_sub(1);
+            }
+            Command::Reverse => self.reverse = !self.reverse,
+            Command::First => self.step = 0,
+            Command::Last => self.step = self.last(),
+            Command::Faster => self.speed = (self.speed + 1).min(SPEEDS.len() - 1),
+            Command::Slower => self.speed = self.speed.saturating_sub(1),
+            Command::Quit => self.quit = true,
+        }
+    }
+
+    // whether playing in the current direction has nowhere to go
+    fn at_end(&self) -> bool {
+        if self.reverse {
+            self.step == 0
+        } else {
+            self.step >= self.last()
+        }
+    }
+
+    // time between redraws while playing
+    pub fn interval(&self) -> Duration {
+        (Duration::from_secs(1) / self.speed() as u32).max(MIN_INTERVAL)
+    }
+
+    // advance by however many steps are played between redraws,
+    // pausing at either end of the recording
+    pub fn tick(&mut self) {
+        if !self.playing {
+            return;
+        }
+        let steps = ((self.speed() as u128 * self.interval().as_millis()) / 1000).max(1) as usize;
+        self.step = if self.reverse {
+            self.step.saturating_sub(steps)
+        } else {
+            (self.step + steps).min(self.last())
+        };
+        if self.at_end() {
+            self.playing = false;
+        }
+    }
+
+    // one line saying where we are
+    pub fn status(&self) -> String {
+        let state = match (self.playing, self.reverse) {
+            (false, _) => "paused",
+            (true, false) => "playing",
+            (true, true) => "rewinding",
+        };
+        format!("step {}/{}  {}  {}/s", self.step, self.last(), state, self.speed())
+    }
+}
+
+// first column and row of the picture to show in a window of the given size,
+// centred on the focus but never scrolled past the edges of the picture
+pub fn scroll(frame: &Frame, width: usize, height: usize) -> (usize, usize) {
+    let (x, y) = frame.focus.unwrap_or((0, 0));
+    let along = |focus: usize, size: usize, window: usize| {
+        focus.saturating_sub(window / 2).min(size.saturating_sub(window))
+    };
+    (
+        along(x, frame.picture.width(), width),
+        along(y, frame.picture.height(), height),
+    )
+}
+
+// the lines of text making up the screen:
+// the caption, the picture, and the status line at the bottom
+pub fn screen(frame: &Frame, player: &Player, width: usize, height: usize) -> Vec<String> {
+    let fit = |line: String| line.chars().take(width).collect::<String>();
+    let rows = height.saturating_sub(3);
+    let (x0, y0) = scroll(frame, width, rows);
+    let mut lines = vec![fit(frame.caption.clone()), String::new()];
+    for y in y0..(y0 + rows).min(frame.picture.height()) {
+        lines.push(frame.picture.row(y).iter().skip(x0).take(width).collect());
+    }
+    while lines.len() + 1 < height {
+        lines.push(String::new());
+    }
+    lines.push(fit(format!("{}  (space, arrows, r, home/end, +/-, q)", player.status())));
+    lines
+}
+
+fn draw(out: &mut impl Write, lines: &[String]) -> io::Result<()> {
+    queue!(out, cursor::MoveTo(0, 0))?;
+    for (y, line) in lines.iter().enumerate() {
+        queue!(
+            out,
+            cursor::MoveTo(0, y as u16),
+            style::Print(line),
+            terminal::Clear(terminal::ClearType::UntilNewLine)
+        )?;
+    }
+    out.flush()
+}
+
+fn play(out: &mut impl Write, recording: &Recording) -> io::Result<()> {
+    let mut player = Player::new(recording.len());
+    let mut shown = None;
+    let mut next_tick = Instant::now() + player.interval();
+    while !player.quit() {
+        if shown != Some(player.step) {
+            let (width, height) = terminal::size()?;
+            let frame = recording.frame(player.step);
+            draw(out, &screen(&frame, &player, width as usize, height as usize))?;
+            shown = Some(player.step);
+        }
+        let timeout = if player.playing {
+            next_tick.saturating_duration_since(Instant::now())
+        } else {
+            Duration::from_secs(1)
+        };
+        if event::poll(timeout)? {
+            match event::read()? {
+                Event::Key(key) if key.kind != KeyEventKind::Release => {
+                    if let Some(command) = command(key) {
+                        player.apply(command);
+                        next_tick = Instant::now() + player.interval();
+                    }
+                }
+                Event::Resize(..) => {
+                    execute!(out, terminal::Clear(terminal::ClearType::All))?;
+                }
+                _ => continue,
+            }
+            // redraw for the new status line
+            shown = None;
+        } else if player.playing {
+            player.tick();
+            next_tick = Instant::now() + player.interval();
+            // the status changes when playing stops at the end
+            if !player.playing {
+                shown = None;
+            }
+        }
+    }
+    Ok(())
+}
+
+// show the recording in the terminal until the user quits
+pub fn run(recording: &Recording) -> io::Result<()> {
+    let mut out = io::stdout();
+    terminal::enable_raw_mode()?;
+    execute!(out, terminal::EnterAlternateScreen, cursor::Hide)?;
+    let result = play(&mut out, recording);
+    // restore the terminal even if playing failed
+    execute!(out, cursor::Show, terminal::LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+    result
+}
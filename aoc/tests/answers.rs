@@ -0,0 +1,52 @@
+// Run every solver against its recorded answers.
+
+use aoc::answers::ANSWERS;
+use aoc::store::Store;
+use aoc::{input_path, solver, YEAR};
+use common::solve;
+
+#[test]
+fn recorded_answers() {
+    let mut failures = Vec::new();
+    for answer in ANSWERS {
+        let path = input_path(answer.day, answer.input);
+        let input = std::fs::read_to_string(&path)
+            .unwrap_or_else(|err| panic!("cannot read {}: {}", path.display(), err));
+        let solve = solver(answer.day).expect("no solver registered");
+        let actual = solve(&input, answer.part).unwrap_or_else(|err| err.to_string());
+        if actual != answer.expected {
+            failures.push(format!(
+                "day {} part {} on {}: expected {:?}, got {:?}",
+                answer.day, answer.part, answer.input, answer.expected, actual
+            ));
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn every_input_file_has_answers() {
+    // every archived input of a registered day should be covered
+    let store = Store::from_env();
+    for day in 1..=25 {
+        if solver(day).is_none() {
+            continue;
+        }
+        for variant in store.variants(YEAR, day).unwrap() {
+            assert!(
+                ANSWERS.iter().any(|a| a.day == day && a.input == variant.name),
+                "day {} has no recorded answers for {}",
+                day,
+                variant.name
+            );
+        }
+    }
+}
+
+#[test]
+fn day6_examples() {
+    for (input, start_of_packet, start_of_message) in day6::EXAMPLES {
+        assert_eq!(solve::<day6::Day6>(input, 1).unwrap(), start_of_packet.to_string(), "{}", input);
+        assert_eq!(solve::<day6::Day6>(input, 2).unwrap(), start_of_message.to_string(), "{}", input);
+    }
+}
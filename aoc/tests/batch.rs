@@ -0,0 +1,118 @@
+use std::fs;
+
+use aoc::batch::{all_passed, run_all, table, Job, Verdict};
+use aoc::{input_path, Day};
+use common::LoadError;
+
+fn job(day: u32, part: u8, variant: &str) -> Job {
+    Job {
+        day: aoc::day(day).unwrap(),
+        part,
+        variant: variant.to_string(),
+        input: fs::read_to_string(input_path(day, variant)).map_err(|err| err.to_string()),
+    }
+}
+
+// day 2, except that part 2 panics and part 1 gets the answer wrong
+fn broken_day() -> &'static Day {
+    fn solve(_: &str, part: u8) -> Result<String, LoadError> {
+        match part {
+            1 => Ok("wrong".to_string()),
+            _ => panic!("part {} is broken", part),
+        }
+    }
+    let day2 = aoc::day(2).unwrap();
+    Box::leak(Box::new(Day {
+        day: 2,
+        solve,
+        answer: day2.answer,
+        check: day2.check,
+        time: day2.time,
+        generate: day2.generate,
+        default_size: day2.default_size,
+        record: None,
+    }))
+}
+
+#[test]
+fn outcomes_are_in_job_order() {
+    let jobs = [(5, 1), (9, 2), (11, 2), (10, 1), (3, 1), (8, 2)]
+        .into_iter()
+        .map(|(day, part)| job(day, part, "example"))
+        .collect::<Vec<Job>>();
+    let outcomes = run_all(&jobs, 4);
+    let order = outcomes.iter().map(|outcome| (outcome.day, outcome.part)).collect::<Vec<_>>();
+    assert_eq!(order, [(5, 1), (9, 2), (11, 2), (10, 1), (3, 1), (8, 2)]);
+    assert!(outcomes.iter().all(|outcome| outcome.verdict == Verdict::Pass));
+    assert_eq!(outcomes[0].answer.as_deref(), Some("CMZ"));
+    assert!(all_passed(&outcomes));
+}
+
+#[test]
+fn failures_are_reported_per_job() {
+    let broken = broken_day();
+    let input = Ok(fs::read_to_string(input_path(2, "example")).unwrap());
+    let jobs = vec![
+        Job { day: broken, part: 1, variant: "example".to_string(), input: input.clone() },
+        Job { day: broken, part: 2, variant: "example".to_string(), input },
+        job(2, 1, "example"),
+        job(2, 1, "example3"),
+        Job { day: aoc::day(2).unwrap(), part: 1, variant: "mine".to_string(), input: Ok("A Y\n".to_string()) },
+    ];
+    let outcomes = run_all(&jobs, 2);
+    assert_eq!(outcomes[0].verdict, Verdict::Fail("15"));
+    assert_eq!(outcomes[1].verdict, Verdict::Error("panicked: part 2 is broken".to_string()));
+    assert_eq!(outcomes[2].verdict, Verdict::Pass);
+    assert!(matches!(outcomes[3].verdict, Verdict::Skipped(_)));
+    assert_eq!(outcomes[4].verdict, Verdict::Unchecked);
+    assert!(!all_passed(&outcomes));
+
+    let table = table(&outcomes);
+    let lines = table.lines().collect::<Vec<&str>>();
+    assert_eq!(lines.len(), 6);
+    assert!(lines[1].contains("wrong") && lines[1].ends_with("FAIL (expected 15)"), "{}", lines[1]);
+    assert!(lines[2].ends_with("ERROR panicked: part 2 is broken"), "{}", lines[2]);
+}
+
+#[test]
+fn every_job_has_a_json_record() {
+    let broken = broken_day();
+    let input = Ok(fs::read_to_string(input_path(2, "example")).unwrap());
+    let jobs = vec![
+        Job { day: broken, part: 1, variant: "example".to_string(), input: input.clone() },
+        Job { day: broken, part: 2, variant: "example".to_string(), input },
+        job(2, 1, "example"),
+        Job { day: aoc::day(2).unwrap(), part: 1, variant: "-".to_string(), input: Err("no input".to_string()) },
+    ];
+    let outcomes = run_all(&jobs, 2);
+    // times vary, so they are left out of the comparison
+    let records = outcomes
+        .iter()
+        .map(|outcome| {
+            let record = outcome.to_json(Some("abc"));
+            let at = record.find(",\"elapsed\":").unwrap();
+            let rest = &record[at + 1..];
+            format!("{}{}", &record[..at], &rest[rest.find(',').unwrap()..])
+        })
+        .collect::<Vec<String>>();
+    assert_eq!(
+        records,
+        [
+            r#"{"day":2,"part":1,"variant":"example","answer":"wrong","input_hash":"abc","verdict":"fail","error":"expected 15"}"#,
+            r#"{"day":2,"part":2,"variant":"example","answer":null,"input_hash":"abc","verdict":"error","error":"panicked: part 2 is broken"}"#,
+            r#"{"day":2,"part":1,"variant":"example","answer":"15","input_hash":"abc","verdict":"pass","error":null}"#,
+            r#"{"day":2,"part":1,"variant":"-","answer":null,"input_hash":"abc","verdict":"skipped","error":"no input"}"#,
+        ]
+    );
+    assert!(outcomes[3].to_json(None).contains(r#""elapsed":0,"input_hash":null,"#));
+}
+
+#[test]
+fn valid_input_without_an_answer_is_an_error() {
+    let monkey = "Monkey 0:\n  Starting items: 5000000000\n  Operation: new = old * old\n  Test: divisible by 2\n    If true: throw to monkey 0\n    If false: throw to monkey 0\n";
+    let jobs = [Job { day: aoc::day(11).unwrap(), part: 1, variant: "big".to_string(), input: Ok(monkey.to_string()) }];
+    let outcomes = run_all(&jobs, 1);
+    let overflow = "worry level overflowed in turn 1, inspected by monkey 0".to_string();
+    assert_eq!(outcomes[0].verdict, Verdict::Error(overflow));
+    assert!(!all_passed(&outcomes));
+}
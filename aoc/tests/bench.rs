@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+use aoc::bench::{is_regression, load_baseline, measurements, merge_baseline, save_baseline, Baseline};
+use common::bench::Timings;
+
+#[test]
+fn regressions_need_threshold_and_noise_floor() {
+    assert!(!is_regression(1000, 1200, 25));
+    assert!(is_regression(1000, 1300, 25));
+    // tiny phases can double without being flagged
+    assert!(!is_regression(10, 40, 25));
+    assert!(!is_regression(1000, 500, 25));
+}
+
+#[test]
+fn baseline_round_trips() {
+    let timings = Timings {
+        parse: Duration::from_micros(12),
+        part1: Duration::from_micros(345),
+        part2: Duration::from_millis(6),
+    };
+    let path = std::env::temp_dir().join(format!("aoc-baseline-{}.tsv", std::process::id()));
+    let mut saved = Baseline::new();
+    merge_baseline(&mut saved, &measurements(4, &timings));
+    save_baseline(&path, &saved).unwrap();
+    let baseline = load_baseline(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(baseline.len(), 3);
+    assert_eq!(baseline[&(4, "parse".to_string())], 12);
+    assert_eq!(baseline[&(4, "part1".to_string())], 345);
+    assert_eq!(baseline[&(4, "part2".to_string())], 6000);
+}
+
+#[test]
+fn saving_one_day_keeps_the_others() {
+    let timings = |micros| Timings {
+        parse: Duration::from_micros(micros),
+        part1: Duration::from_micros(micros + 1),
+        part2: Duration::from_micros(micros + 2),
+    };
+    let path = std::env::temp_dir().join(format!("aoc-baseline-merge-{}.tsv", std::process::id()));
+    let mut baseline = Baseline::new();
+    for day in 1..=3 {
+        merge_baseline(&mut baseline, &measurements(day, &timings(day as u64 * 100)));
+    }
+    save_baseline(&path, &baseline).unwrap();
+
+    // as bench 2 --save does
+    let mut baseline = load_baseline(&path).unwrap();
+    merge_baseline(&mut baseline, &measurements(2, &timings(7)));
+    save_baseline(&path, &baseline).unwrap();
+    let text = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(
+        text,
+        "day\tphase\tmicros\n\
+         1\tparse\t100\n1\tpart1\t101\n1\tpart2\t102\n\
+         2\tparse\t7\n2\tpart1\t8\n2\tpart2\t9\n\
+         3\tparse\t300\n3\tpart1\t301\n3\tpart2\t302\n"
+    );
+}
+
+#[test]
+fn every_day_can_be_timed() {
+    for day in aoc::DAYS {
+        let input = std::fs::read_to_string(aoc::default_input(day.day)).unwrap();
+        assert!((day.time)(&input, 1).is_ok(), "day {}", day.day);
+    }
+}
@@ -0,0 +1,39 @@
+use std::fs;
+
+use aoc::export::{animation, every, image};
+use aoc::input_path;
+
+#[test]
+fn every_ends_with_the_last_step() {
+    assert_eq!(every(10, 3), [0, 3, 6, 9]);
+    assert_eq!(every(11, 3), [0, 3, 6, 9, 10]);
+    assert_eq!(every(3, 0), [0, 1, 2]);
+    assert!(every(0, 2).is_empty());
+}
+
+#[test]
+fn crt_is_drawn_in_lit_and_dark_cells() {
+    let input = fs::read_to_string(input_path(10, "example")).unwrap();
+    let recording = (aoc::day(10).unwrap().record.unwrap())(&input, 2).unwrap();
+    let frame = recording.frame(recording.len() - 1);
+    let image = image(&frame, 3);
+    assert_eq!((image.width(), image.height()), (40 * 3, frame.picture.height() * 3));
+    // "##..##.." along the top row
+    let top = (0..8).map(|x| image.pixels[(x * 3 + 1, 1)]).collect::<Vec<u8>>();
+    let (lit, dark) = (top[0], top[2]);
+    assert_ne!(lit, dark);
+    assert_eq!(top, [lit, lit, dark, dark, lit, lit, dark, dark]);
+}
+
+#[test]
+fn animation_frames_share_a_size() {
+    let input = fs::read_to_string(input_path(9, "example2")).unwrap();
+    let recording = (aoc::day(9).unwrap().record.unwrap())(&input, 2).unwrap();
+    let steps = every(recording.len(), 10);
+    let animation = animation(&recording, &steps, 2, 5);
+    assert_eq!(animation.len(), steps.len());
+    let gif = animation.encode();
+    assert_eq!(&gif[..6], b"GIF89a");
+    let width = u16::from_le_bytes([gif[6], gif[7]]) as usize;
+    assert_eq!(width, recording.frame(0).picture.width() * 2);
+}
@@ -0,0 +1,38 @@
+use aoc::DAYS;
+
+#[test]
+fn generated_inputs_are_solvable() {
+    for day in DAYS {
+        for seed in 0..5 {
+            let input = (day.generate)(seed, day.default_size);
+            for part in [1, 2] {
+                if let Err(err) = (day.solve)(&input, part) {
+                    panic!("day {} seed {} part {}:\n{}", day.day, seed, part, err);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn small_inputs_are_solvable() {
+    for day in DAYS {
+        for size in 0..4 {
+            let input = (day.generate)(1, size);
+            for part in [1, 2] {
+                if let Err(err) = (day.solve)(&input, part) {
+                    panic!("day {} size {} part {}:\n{}", day.day, size, part, err);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn generation_is_seeded() {
+    for day in DAYS {
+        let input = (day.generate)(42, day.default_size);
+        assert_eq!(input, (day.generate)(42, day.default_size), "day {}", day.day);
+        assert_ne!(input, (day.generate)(43, day.default_size), "day {}", day.day);
+    }
+}
@@ -0,0 +1,43 @@
+use std::process::Command;
+
+use aoc::input_path;
+
+// run day 9 on its example, returning stdout and stderr
+fn run_day9(level: &[&str]) -> (String, String) {
+    let input = input_path(9, "example");
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(level)
+        .args(["run", "9", "--part", "1", "--input"])
+        .arg(input)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    (
+        String::from_utf8(output.stdout).unwrap(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+#[test]
+fn quiet_by_default() {
+    let (stdout, stderr) = run_day9(&[]);
+    assert_eq!(stdout, "# Day 9 part 1\n13\n");
+    assert_eq!(stderr, "");
+    assert_eq!(run_day9(&["--quiet"]).1, "");
+}
+
+#[test]
+fn verbose_prints_summaries() {
+    let (stdout, stderr) = run_day9(&["-v"]);
+    assert_eq!(stdout, "# Day 9 part 1\n13\n");
+    assert_eq!(stderr, "2 knots: tail visited 13 squares\n");
+}
+
+#[test]
+fn trace_prints_every_step() {
+    let (stdout, stderr) = run_day9(&["--trace"]);
+    assert_eq!(stdout, "# Day 9 part 1\n13\n");
+    assert!(stderr.contains("== R 4 =="));
+    assert!(stderr.contains("follow: k=1 head=(1, 0) tail=(0, 0)"));
+    assert!(stderr.ends_with("2 knots: tail visited 13 squares\n"));
+}
@@ -0,0 +1,33 @@
+// Malformed inputs are reported with the location of the offending token.
+
+use aoc::day;
+
+// parse an input for the day, expecting an error at the given location
+fn assert_error_at(day: u32, input: &str, line: usize, column: usize) {
+    let err = (self::day(day).unwrap().check)(input).expect_err("expected a parse error");
+    assert_eq!((err.line, err.column), (line, column), "day {}: {}", day, err);
+}
+
+#[test]
+fn error_locations() {
+    assert_error_at(1, "100\n2oo\n", 2, 2);
+    assert_error_at(2, "A Y\nB Q\n", 2, 3);
+    assert_error_at(3, "vJrwpWtwJgWrhcsFMMfFFhFp\nabc\n", 2, 1);
+    assert_error_at(4, "2-4,6-8\n2-3,x-5\n", 2, 5);
+    assert_error_at(5, "[A]\n 1\n\nmove 2 from 1 to 1\n", 4, 6);
+    assert_error_at(6, "abc\ndef\n", 2, 1);
+    assert_error_at(7, "$ ls\n10 a\n$ cd a\n", 3, 6);
+    assert_error_at(8, "123\n45\n", 2, 1);
+    assert_error_at(9, "R 4\nX 1\n", 2, 1);
+    assert_error_at(10, "noop\naddx\n", 2, 5);
+    assert_error_at(11, "Monkey 1:\n", 1, 8);
+}
+
+#[test]
+fn error_display() {
+    let err = (day(4).unwrap().check)("2-4,6-8\n2-3,x-5\n").unwrap_err().in_file("day4/input");
+    assert_eq!(
+        err.to_string(),
+        "day4/input:2:5: expected a number, found `x`\n  |\n2 | 2-3,x-5\n  |     ^"
+    );
+}
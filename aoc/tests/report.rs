@@ -0,0 +1,78 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use aoc::report::{input_hash, stream_hash, Record};
+use common::json;
+
+#[test]
+fn records_are_json() {
+    let record = Record {
+        day: 10,
+        part: 2,
+        answer: "#.\n.#".to_string(),
+        elapsed: Duration::from_micros(1234),
+        input_hash: input_hash(""),
+    };
+    assert_eq!(
+        record.to_json(),
+        r##"{"day":10,"part":2,"answer":"#.\n.#","elapsed":1234,"input_hash":"cbf29ce484222325"}"##
+    );
+}
+
+#[test]
+fn strings_are_escaped() {
+    assert_eq!(json::string("CMZ"), "\"CMZ\"");
+    assert_eq!(json::string("a\"b\\c\td\u{1}"), r#""a\"b\\c\td\u0001""#);
+    let object = json::object(&[("a\nb", json::array(["1".to_string(), json::string("c")]))]);
+    assert_eq!(object, r#"{"a\nb":[1,"c"]}"#);
+}
+
+#[test]
+fn input_hash_is_fnv1a() {
+    assert_eq!(input_hash(""), "cbf29ce484222325");
+    assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    // in small reads, as from a file bigger than the buffer
+    let text = "$ cd /\n$ ls\n".repeat(1000);
+    let reader = std::io::BufReader::with_capacity(7, text.as_bytes());
+    assert_eq!(stream_hash(reader).unwrap(), input_hash(&text));
+}
+
+#[test]
+fn runner_prints_one_record_per_part() {
+    let input = aoc::input_path(2, "example");
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "2", "--format", "json", "--input"])
+        .arg(&input)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let hash = input_hash(&std::fs::read_to_string(&input).unwrap());
+    let lines = stdout.lines().collect::<Vec<&str>>();
+    assert_eq!(lines.len(), 2);
+    for (line, (part, answer)) in lines.iter().zip([(1, 15), (2, 12)]) {
+        let prefix = format!("{{\"day\":2,\"part\":{},\"answer\":\"{}\",\"elapsed\":", part, answer);
+        assert!(line.starts_with(&prefix), "{}", line);
+        assert!(line.ends_with(&format!(",\"input_hash\":\"{}\"}}", hash)), "{}", line);
+    }
+}
+
+#[test]
+fn runner_fails_when_a_part_has_no_answer() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "11", "--input", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let monkey = "Monkey 0:\n  Starting items: 5000000000\n  Operation: new = old * old\n  Test: divisible by 2\n    If true: throw to monkey 0\n    If false: throw to monkey 0\n";
+    child.stdin.take().unwrap().write_all(monkey.as_bytes()).unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(!output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("day 11 part 1: worry level overflowed in turn 1"), "{}", stderr);
+    assert!(stderr.contains("day 11 part 2: worry level overflowed in turn 1"), "{}", stderr);
+}
@@ -0,0 +1,120 @@
+/*
+Property tests of the parsers against the generators: a generated
+puzzle written out as text must parse back into the same model, and
+no input at all, however mangled, may make a parser panic.
+*/
+
+use std::fmt::Debug;
+
+use common::generate::{Generator, StdRng};
+use proptest::prelude::*;
+use rand::SeedableRng;
+
+// generate a puzzle, write it out and parse it back
+fn round_trip<S: Generator>(seed: u64, size: usize) -> (S::Input, S::Input, String) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let model = S::generate(&mut rng, size);
+    let text = S::to_text(&model);
+    match S::parse(&text) {
+        Ok(parsed) => (model, parsed, text),
+        Err(err) => panic!("generated input does not parse:\n{}\n{}", err, text),
+    }
+}
+
+// the parsed model must equal the generated one
+fn check_model<S: Generator>(seed: u64, size: usize)
+where
+    S::Input: PartialEq + Debug,
+{
+    let (model, parsed, _) = round_trip::<S>(seed, size);
+    assert_eq!(model, parsed);
+}
+
+// for models without equality, writing the parsed model out again
+// must give back the same text
+fn check_text<S: Generator>(seed: u64, size: usize) {
+    let (_, parsed, text) = round_trip::<S>(seed, size);
+    assert_eq!(S::to_text(&parsed), text);
+}
+
+// ways of damaging an input
+#[derive(Clone, Debug)]
+enum Edit {
+    Delete(usize),
+    Insert(usize, char),
+    Replace(usize, char),
+}
+
+fn edit() -> impl Strategy<Value = Edit> {
+    // mostly characters which appear in puzzle inputs, to get past the
+    // first few checks of each parser, and now and then anything at all
+    let c = prop_oneof![
+        4 => prop::sample::select(" \n\r0123456789-+,:[]$/.ABCXYZRLUDadmovefrtlsi".chars().collect::<Vec<char>>()),
+        1 => any::<char>(),
+    ];
+    prop_oneof![
+        any::<usize>().prop_map(Edit::Delete),
+        (any::<usize>(), c.clone()).prop_map(|(i, c)| Edit::Insert(i, c)),
+        (any::<usize>(), c).prop_map(|(i, c)| Edit::Replace(i, c)),
+    ]
+}
+
+// apply edits at character positions (modulo the length of the text)
+fn apply(text: &str, edits: &[Edit]) -> String {
+    let mut chars = text.chars().collect::<Vec<char>>();
+    for edit in edits {
+        let len = chars.len().max(1);
+        match *edit {
+            Edit::Delete(i) if !chars.is_empty() => {
+                chars.remove(i % len);
+            }
+            Edit::Delete(_) => {}
+            Edit::Insert(i, c) => chars.insert(i % (chars.len() + 1), c),
+            Edit::Replace(i, c) if !chars.is_empty() => chars[i % len] = c,
+            Edit::Replace(..) => {}
+        }
+    }
+    chars.into_iter().collect()
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn models_round_trip(seed in any::<u64>(), size in 0usize..40) {
+        check_model::<day1::Day1>(seed, size);
+        check_model::<day2::Day2>(seed, size);
+        check_text::<day3::Day3>(seed, size);
+        check_model::<day4::Day4>(seed, size);
+        check_model::<day5::Day5>(seed, size);
+        check_model::<day6::Day6>(seed, size);
+        check_text::<day7::Day7>(seed, size);
+        check_model::<day8::Day8>(seed, size);
+        check_model::<day9::Day9>(seed, size);
+        check_model::<day10::Day10>(seed, size);
+        check_model::<day11::Day11>(seed, size.min(12));
+    }
+
+    #[test]
+    fn parsers_never_panic_on_bytes(bytes in prop::collection::vec(any::<u8>(), 0..200)) {
+        let text = String::from_utf8_lossy(&bytes);
+        for day in aoc::DAYS {
+            let _ = (day.check)(&text);
+        }
+    }
+
+    #[test]
+    fn parsers_never_panic_on_damaged_inputs(
+        seed in any::<u64>(),
+        size in 1usize..12,
+        edits in prop::collection::vec(edit(), 1..6),
+    ) {
+        for day in aoc::DAYS {
+            let text = apply(&(day.generate)(seed, size), &edits);
+            // any error must also be displayable
+            if let Err(err) = (day.check)(&text) {
+                let _ = err.to_string();
+            }
+        }
+    }
+}
@@ -0,0 +1,43 @@
+// Puzzle input can come from a file, standard input or memory.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use aoc::input_path;
+use common::{LoadError, Solution, Source};
+use day4::Day4;
+
+#[test]
+fn file_and_text_agree() {
+    let path = input_path(4, "real");
+    let text = std::fs::read_to_string(&path).unwrap();
+    let from_file = Day4::load(&Source::File(path)).unwrap();
+    let from_text = Day4::load(&Source::text(&text)).unwrap();
+    assert_eq!(from_file, from_text);
+}
+
+#[test]
+fn errors_name_the_source() {
+    match Day4::load(&Source::text("1-2,3\n")) {
+        Err(LoadError::Parse(err)) => assert_eq!(err.file.as_deref(), Some("<text>")),
+        _ => panic!("expected a parse error"),
+    }
+    match Day4::load(&Source::from_arg("no/such/file")) {
+        Err(LoadError::Io(name, _)) => assert_eq!(name, "no/such/file"),
+        _ => panic!("expected an io error"),
+    }
+}
+
+#[test]
+fn runner_reads_stdin() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "6", "--part", "1", "--input", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(b"bvwbjplbgvbhsrlpgdmjqwftvncz\n").unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "# Day 6 part 1\n5\n");
+}
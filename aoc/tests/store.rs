@@ -0,0 +1,124 @@
+// The input archive, and fetching from a directory or web server standing in
+// for the puzzle site.
+
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::thread;
+
+use aoc::report::input_hash;
+use aoc::store::{Remote, Store};
+
+// empty scratch directory for one test
+fn scratch(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-store-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// serve the given pages, answering 404 for anything else,
+// returning the base URL
+fn serve(pages: &'static [(&'static str, &'static str)]) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut request = String::new();
+            let mut reader = BufReader::new(&stream);
+            reader.read_line(&mut request).unwrap();
+            // skip the rest of the headers
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 2 {
+                line.clear();
+            }
+            let path = request.split_whitespace().nth(1).unwrap_or("");
+            let response = match pages.iter().find(|(page, _)| *page == path) {
+                Some((_, body)) => format!("HTTP/1.0 200 OK\r\n\r\n{}", body),
+                None => "HTTP/1.0 404 Not Found\r\n\r\n".to_string(),
+            };
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+    url
+}
+
+#[test]
+fn lists_variants_with_checksums() {
+    let dir = scratch("list");
+    let store = Store::new(&dir);
+    assert!(store.variants(2022, 4).unwrap().is_empty());
+    for (variant, text) in [("example", "1-2,3-4\n"), ("real", "5-6,7-8\n"), ("example2", "")] {
+        let path = store.path(2022, 4, variant);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, text).unwrap();
+    }
+    let variants = store.variants(2022, 4).unwrap();
+    let names = variants.iter().map(|v| v.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, ["real", "example", "example2"]);
+    assert_eq!(variants[1].checksum, input_hash("1-2,3-4\n"));
+    assert_eq!(variants[1].path, dir.join("2022").join("day4").join("example"));
+    assert_eq!(store.default_variant(2022, 4).unwrap(), variants[0].path);
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn missing_variants_are_errors() {
+    let dir = scratch("missing");
+    let store = Store::new(&dir);
+    assert!(store.resolve(2022, 1, "real").is_err());
+    assert!(store.default_variant(2022, 1).is_err());
+    // variant names cannot leave the archive
+    assert!(store.resolve(2022, 1, "../../etc/passwd").is_err());
+    assert!(store.resolve(2022, 1, "").is_err());
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn fetches_from_a_directory() {
+    let remote = scratch("remote-dir");
+    let local = scratch("local-dir");
+    let remote_path = Store::new(&remote).path(2022, 6, "real");
+    fs::create_dir_all(remote_path.parent().unwrap()).unwrap();
+    fs::write(&remote_path, "abcd\n").unwrap();
+
+    let store = Store::new(&local).with_remote(Remote::from_arg(remote.to_str().unwrap()));
+    assert_eq!(store.read(2022, 6, "real").unwrap(), "abcd\n");
+    // now cached in the local archive
+    assert_eq!(fs::read_to_string(store.path(2022, 6, "real")).unwrap(), "abcd\n");
+    assert!(store.read(2022, 6, "example").is_err());
+    fs::remove_dir_all(remote).unwrap();
+    fs::remove_dir_all(local).unwrap();
+}
+
+#[test]
+fn only_missing_real_inputs_fall_back_to_the_example() {
+    let remote = scratch("remote-broken");
+    let local = scratch("local-broken");
+    let store = Store::new(&local).with_remote(Remote::from_arg(remote.to_str().unwrap()));
+    let example = store.path(2022, 3, "example");
+    fs::create_dir_all(example.parent().unwrap()).unwrap();
+    fs::write(&example, "abc\n").unwrap();
+    assert_eq!(store.default_variant(2022, 3).unwrap(), example);
+    // a real input which is there but cannot be read is not missing
+    fs::create_dir_all(Store::new(&remote).path(2022, 3, "real")).unwrap();
+    let err = store.default_variant(2022, 3).unwrap_err();
+    assert_ne!(err.kind(), std::io::ErrorKind::NotFound, "{}", err);
+    fs::remove_dir_all(remote).unwrap();
+    fs::remove_dir_all(local).unwrap();
+}
+
+#[test]
+fn fetches_from_a_web_server() {
+    let url = serve(&[("/2022/day/2/input", "A Y\n"), ("/2022/day/2/example", "B X\n")]);
+    let local = scratch("local-http");
+    let store = Store::new(&local).with_remote(Remote::from_arg(&url));
+    assert_eq!(store.read(2022, 2, "real").unwrap(), "A Y\n");
+    assert_eq!(store.read(2022, 2, "example").unwrap(), "B X\n");
+    assert!(store.read(2022, 2, "example2").is_err());
+    let names = store.variants(2022, 2).unwrap().into_iter().map(|v| v.name).collect::<Vec<_>>();
+    assert_eq!(names, ["real", "example"]);
+    fs::remove_dir_all(local).unwrap();
+}
@@ -0,0 +1,139 @@
+use std::fs;
+
+use aoc::visualize::{screen, Command, Player};
+use aoc::{input_path, solver, DAYS};
+use common::simulate::Recording;
+
+fn record(day: u32, variant: &str, part: u8) -> (String, Recording) {
+    let input = fs::read_to_string(input_path(day, variant)).unwrap();
+    let record = aoc::day(day).unwrap().record.unwrap();
+    let recording = record(&input, part).unwrap();
+    (input, recording)
+}
+
+fn picture(recording: &Recording, step: usize) -> String {
+    recording.frame(step).picture.render(|&c| c)
+}
+
+#[test]
+fn day5_ends_with_the_top_crates() {
+    for (part, top) in [(1, "CMZ"), (2, "MCD")] {
+        let (_, recording) = record(5, "example", part);
+        // the starting stacks and one frame per move
+        assert_eq!(recording.len(), 5);
+        assert_eq!(recording.frame(0).caption, "starting stacks");
+        let last = recording.frame(4);
+        assert_eq!(last.caption, "move 1 from 1 to 2");
+        let tops = (0..3)
+            .map(|stack| {
+                let column = stack * 4 + 1;
+                (0..last.picture.height())
+                    .map(|y| last.picture[(column, y)])
+                    .find(|c| c.is_ascii_uppercase())
+                    .unwrap()
+            })
+            .collect::<String>();
+        assert_eq!(tops, top, "part {}", part);
+    }
+}
+
+#[test]
+fn day9_tail_visits_the_answer() {
+    for part in [1, 2] {
+        let (input, recording) = record(9, "example2", part);
+        let answer = solver(9).unwrap()(&input, part).unwrap();
+        let last = recording.frame(recording.len() - 1);
+        assert!(last.caption.ends_with(&format!(": {} squares visited", answer)), "{}", last.caption);
+        let focus = last.focus.unwrap();
+        assert_eq!(last.picture[focus], 'H');
+    }
+}
+
+#[test]
+fn day10_crt_shows_the_answer() {
+    let (input, recording) = record(10, "example", 2);
+    let answer = solver(10).unwrap()(&input, 2).unwrap();
+    assert_eq!(recording.len(), 240);
+    let last = picture(&recording, 239);
+    assert!(last.starts_with(&answer));
+    assert!(picture(&recording, 0).starts_with("#......"));
+}
+
+#[test]
+fn day11_counts_inspections() {
+    let (_, recording) = record(11, "example", 2);
+    assert_eq!(recording.len(), 10001);
+    let last = picture(&recording, 10000);
+    assert!(last.contains("Monkey 0 (52166 inspected)"), "{}", last);
+    assert!(last.contains("Monkey 3 (52013 inspected)"), "{}", last);
+    let (_, recording) = record(11, "example", 1);
+    assert_eq!(recording.frame(1).caption, "round 1: monkey 0 throws 500 to monkey 3");
+}
+
+#[test]
+fn every_recording_can_be_drawn() {
+    for day in DAYS.iter().filter(|day| day.record.is_some()) {
+        let input = (day.generate)(0, 10);
+        for part in [1, 2] {
+            let recording = (day.record.unwrap())(&input, part).unwrap();
+            let player = Player::new(recording.len());
+            for step in 0..recording.len() {
+                let lines = screen(&recording.frame(step), &player, 20, 8);
+                assert_eq!(lines.len(), 8, "day {} step {}", day.day, step);
+                assert!(lines.iter().all(|line| line.chars().count() <= 20));
+            }
+        }
+    }
+}
+
+#[test]
+fn player_steps_and_plays() {
+    let mut player = Player::new(10);
+    player.apply(Command::Back);
+    assert_eq!(player.step, 0);
+    player.apply(Command::Forward);
+    player.apply(Command::Forward);
+    assert_eq!(player.step, 2);
+    player.apply(Command::Last);
+    player.apply(Command::Forward);
+    assert_eq!(player.step, 9);
+
+    // playing from the end starts over, and stops at the end
+    player.apply(Command::PlayPause);
+    assert!(player.playing);
+    assert_eq!(player.step, 0);
+    for _ in 0..20 {
+        player.tick();
+    }
+    assert_eq!(player.step, 9);
+    assert!(!player.playing);
+
+    // rewinding plays back to the start
+    player.apply(Command::Reverse);
+    player.apply(Command::PlayPause);
+    player.tick();
+    assert_eq!(player.step, 8);
+    player.apply(Command::PlayPause);
+    player.tick();
+    assert_eq!(player.step, 8);
+
+    player.apply(Command::Quit);
+    assert!(player.quit());
+}
+
+#[test]
+fn faster_playing_takes_bigger_steps() {
+    let mut player = Player::new(100000);
+    let slow = player.speed();
+    for _ in 0..20 {
+        player.apply(Command::Faster);
+    }
+    assert!(player.speed() > slow);
+    player.apply(Command::PlayPause);
+    player.tick();
+    assert!(player.step > 1);
+    for _ in 0..20 {
+        player.apply(Command::Slower);
+    }
+    assert_eq!(player.speed(), 1);
+}
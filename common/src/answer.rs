@@ -0,0 +1,51 @@
+/*
+What the parts of a puzzle give back: an answer to print, or why valid
+input has no answer that can be given, such as a total which no longer
+fits in its type. Parts which cannot fail give their answer as it is;
+parts which can give a Result, with the error as a SolveError.
+*/
+
+use std::fmt;
+
+// why a part could not be solved from input which parsed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SolveError {
+    pub message: String,
+}
+
+impl SolveError {
+    pub fn new(message: &str) -> SolveError {
+        SolveError { message: message.to_string() }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+pub trait Answer {
+    // the answer as it is printed
+    fn answer(&self) -> Result<String, SolveError>;
+}
+
+macro_rules! answer_as_text {
+    ($($t:ty),*) => {
+        $(impl Answer for $t {
+            fn answer(&self) -> Result<String, SolveError> {
+                Ok(self.to_string())
+            }
+        })*
+    };
+}
+
+answer_as_text!(i32, i64, isize, u32, u64, usize, String);
+
+impl<T: Answer> Answer for Result<T, SolveError> {
+    fn answer(&self) -> Result<String, SolveError> {
+        self.as_ref().map_err(|err| err.clone())?.answer()
+    }
+}
@@ -0,0 +1,44 @@
+/*
+Timing of each phase of a solution: parsing the input,
+and solving each of the two parts.
+*/
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::{ParseError, Solution};
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+// time each phase of the solution, taking the fastest of several runs
+pub fn time<S: Solution>(input: &str, runs: usize) -> Result<Timings, ParseError> {
+    let mut best: Option<Timings> = None;
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        let parsed = S::parse(black_box(input))?;
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        black_box(S::part1(&parsed));
+        let part1 = start.elapsed();
+
+        let start = Instant::now();
+        black_box(S::part2(&parsed));
+        let part2 = start.elapsed();
+
+        best = Some(match best {
+            None => Timings { parse, part1, part2 },
+            Some(best) => Timings {
+                parse: best.parse.min(parse),
+                part1: best.part1.min(part1),
+                part2: best.part2.min(part2),
+            },
+        });
+    }
+    Ok(best.unwrap())
+}
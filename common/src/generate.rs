@@ -0,0 +1,37 @@
+/*
+Synthetic puzzle inputs.
+
+Every day can generate a random but valid puzzle model and write it out
+in the puzzle's text format, so that the solvers can be run against
+inputs other than the one real input (and, parsed back in, the model
+checks the parser).
+
+Generation is seeded, so the same seed and size always give the same
+input. What the size means is up to each day: the number of elves,
+rounds, rucksack groups, moves, grid rows and so on.
+*/
+
+use rand::SeedableRng;
+
+use crate::Solution;
+
+pub use rand::rngs::StdRng;
+pub use rand::seq::SliceRandom;
+pub use rand::Rng;
+
+pub trait Generator: Solution {
+    // size used when none is given, close to that of the real input
+    const DEFAULT_SIZE: usize;
+
+    // a random puzzle of about the given size
+    fn generate(rng: &mut StdRng, size: usize) -> Self::Input;
+
+    // write a puzzle out in the puzzle text format
+    fn to_text(input: &Self::Input) -> String;
+}
+
+// puzzle text generated from the given seed
+pub fn generate<S: Generator>(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    S::to_text(&S::generate(&mut rng, size))
+}
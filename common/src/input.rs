@@ -0,0 +1,100 @@
+/*
+Where puzzle input comes from: a file, standard input, or text which
+is already in memory (for tests and generated puzzles).
+*/
+
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::PathBuf;
+
+use crate::{ParseError, SolveError};
+
+#[derive(Clone, Debug)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+    Text(String),
+}
+
+impl Source {
+    // interpret a command line argument: "-" is standard input,
+    // anything else is a path
+    pub fn from_arg(arg: &str) -> Source {
+        match arg {
+            "-" => Source::Stdin,
+            _ => Source::File(PathBuf::from(arg)),
+        }
+    }
+
+    pub fn text(text: &str) -> Source {
+        Source::Text(text.to_string())
+    }
+
+    // name to use for the input in diagnostics
+    pub fn name(&self) -> String {
+        match self {
+            Source::File(path) => path.display().to_string(),
+            Source::Stdin => "<stdin>".to_string(),
+            Source::Text(_) => "<text>".to_string(),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, LoadError> {
+        let io_error = |err| LoadError::Io(self.name(), err);
+        match self {
+            Source::File(path) => std::fs::read_to_string(path).map_err(io_error),
+            Source::Stdin => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text).map_err(io_error)?;
+                Ok(text)
+            }
+            Source::Text(text) => Ok(text.clone()),
+        }
+    }
+
+    // the input as a stream, for days which can parse it a line at a time
+    // without holding all of it in memory
+    pub fn open(&self) -> Result<Box<dyn BufRead + '_>, LoadError> {
+        match self {
+            Source::File(path) => match File::open(path) {
+                Ok(file) => Ok(Box::new(BufReader::new(file))),
+                Err(err) => Err(LoadError::Io(self.name(), err)),
+            },
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+            Source::Text(text) => Ok(Box::new(text.as_bytes())),
+        }
+    }
+}
+
+// failure to read or parse an input, or to solve it
+#[derive(Debug)]
+pub enum LoadError {
+    Io(String, io::Error),
+    Parse(ParseError),
+    Solve(SolveError),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LoadError::Io(name, err) => write!(f, "cannot read {}: {}", name, err),
+            LoadError::Parse(err) => write!(f, "{}", err),
+            LoadError::Solve(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+impl From<ParseError> for LoadError {
+    fn from(err: ParseError) -> LoadError {
+        LoadError::Parse(err)
+    }
+}
+
+impl From<SolveError> for LoadError {
+    fn from(err: SolveError) -> LoadError {
+        LoadError::Solve(err)
+    }
+}
@@ -0,0 +1,35 @@
+// JSON written by hand, for the few places which print it:
+// run's records, day7's filesystem and day1's statistics
+
+// quote a string for JSON, escaping anything that needs it
+// (answers such as day10's CRT image span several lines)
+pub fn string(s: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if (c as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+// an object of the given fields in order, each value already JSON
+pub fn object(fields: &[(&str, String)]) -> String {
+    let fields = fields
+        .iter()
+        .map(|(name, value)| format!("{}:{}", string(name), value))
+        .collect::<Vec<String>>();
+    format!("{{{}}}", fields.join(","))
+}
+
+// an array of values which are already JSON
+pub fn array(values: impl IntoIterator<Item = String>) -> String {
+    format!("[{}]", values.into_iter().collect::<Vec<String>>().join(","))
+}
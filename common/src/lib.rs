@@ -0,0 +1,85 @@
+/*
+Code shared between the days.
+
+Every day implements the Solution trait, which splits a puzzle into
+parsing the input text into a typed model, and then solving each of
+the two parts against that model.
+*/
+
+use std::time::{Duration, Instant};
+
+pub mod answer;
+pub mod bench;
+pub mod generate;
+pub mod input;
+pub mod json;
+pub mod log;
+pub mod parse;
+pub mod simulate;
+
+pub use answer::{Answer, SolveError};
+pub use input::{LoadError, Source};
+pub use parse::ParseError;
+
+pub trait Solution {
+    // the parsed puzzle input
+    type Input;
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    // parse the puzzle input text into the model
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    // read and parse the puzzle input from a file, stdin or text
+    fn load(source: &Source) -> Result<Self::Input, LoadError> {
+        let text = source.read()?;
+        Self::parse(&text).map_err(|err| LoadError::Parse(err.in_file(&source.name())))
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1;
+
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+// solve one part of the puzzle from its parsed input,
+// returning the answer formatted as a string
+fn solve_part<S: Solution>(input: &S::Input, part: u8) -> Result<String, SolveError> {
+    match part {
+        1 => S::part1(input).answer(),
+        2 => S::part2(input).answer(),
+        _ => panic!("there is no part {}", part),
+    }
+}
+
+// parse the input and solve one part of the puzzle,
+// returning the answer formatted as a string
+pub fn solve<S: Solution>(input: &str, part: u8) -> Result<String, LoadError> {
+    let input = S::parse(input)?;
+    Ok(solve_part::<S>(&input, part)?)
+}
+
+// parse the input without solving it, only checking that it is valid
+pub fn check<S: Solution>(input: &str) -> Result<(), ParseError> {
+    S::parse(input).map(|_| ())
+}
+
+// the answer to one part (or why there is none) and the time it took
+pub type Timed = (Result<String, SolveError>, Duration);
+
+// load the input once, which some days (such as day 7) do without
+// holding all of it in memory, and solve the given parts against it,
+// each answer (or why there is none) with the time taken to load the
+// input and solve the part
+pub fn answer<S: Solution>(source: &Source, parts: &[u8]) -> Result<Vec<Timed>, LoadError> {
+    let start = Instant::now();
+    let input = S::load(source)?;
+    let loading = start.elapsed();
+    Ok(parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = solve_part::<S>(&input, part);
+            (answer, loading + start.elapsed())
+        })
+        .collect())
+}
@@ -0,0 +1,62 @@
+/*
+Output levels shared by all of the days.
+
+Solvers narrate what they are doing through the `verbose!` and
+`trace!` macros rather than printing directly, so that by default
+only the answers are shown:
+
+    quiet:   answers only (the default)
+    verbose: short summaries, such as the final state of a puzzle
+    trace:   step-by-step dumps of every move, cycle or turn
+
+Narration goes to standard error, keeping standard output for answers.
+*/
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Quiet,
+    Verbose,
+    Trace,
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Quiet as u8);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+    match LEVEL.load(Ordering::Relaxed) {
+        0 => Level::Quiet,
+        1 => Level::Verbose,
+        _ => Level::Trace,
+    }
+}
+
+// should output at this level be shown?
+// Useful to skip building output which would not be shown.
+pub fn enabled(level: Level) -> bool {
+    level <= self::level()
+}
+
+// print a summary line, shown at verbose level and above
+#[macro_export]
+macro_rules! verbose {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Verbose) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+// print a step-by-step line, shown at trace level only
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace) {
+            eprintln!($($arg)*);
+        }
+    };
+}
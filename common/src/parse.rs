@@ -0,0 +1,214 @@
+/*
+Parse errors with location information, and a small cursor over a line
+of input which produces them.
+
+Errors are reported like a compiler would:
+
+    inputs/2022/day4/real:3:5: expected a number, found `x`
+      |
+    3 | 12-x,4-5
+      |    ^
+*/
+
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    // name of the input, filled in by whoever knows it
+    pub file: Option<String>,
+    // 1-based line and column (in chars) of the offending token
+    pub line: usize,
+    pub column: usize,
+    // description of what the parser was expecting
+    pub expected: String,
+    // the offending token, or "end of line" / "end of input"
+    pub found: String,
+    // full text of the offending line
+    pub text: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: &str, found: &str, text: &str) -> ParseError {
+        ParseError {
+            file: None,
+            line,
+            column,
+            expected: expected.to_string(),
+            found: found.to_string(),
+            text: text.to_string(),
+        }
+    }
+
+    // error for input which stops before the parser is done,
+    // where `lines` is the number of lines read so far
+    pub fn end_of_input(lines: usize, expected: &str) -> ParseError {
+        ParseError::new(lines + 1, 1, expected, "end of input", "")
+    }
+
+    // attach the name of the input the error was found in
+    pub fn in_file(mut self, file: &str) -> ParseError {
+        self.file = Some(file.to_string());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let file = self.file.as_deref().unwrap_or("input");
+        let found = if self.found.starts_with("end of") {
+            self.found.clone()
+        } else {
+            format!("`{}`", self.found)
+        };
+        writeln!(f, "{}:{}:{}: expected {}, found {}", file, self.line, self.column, self.expected, found)?;
+        // gutter wide enough for the line number
+        let gutter = " ".repeat(self.line.to_string().len());
+        let width = if self.found.starts_with("end of") { 1 } else { self.found.chars().count().max(1) };
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(f, "{} | {}{}", gutter, " ".repeat(self.column - 1), "^".repeat(width))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// iterate over the lines of the input, numbering them from 1
+pub fn lines(text: &str) -> impl Iterator<Item = Line<'_>> {
+    text.lines().enumerate().map(|(i, line)| Line::new(i + 1, line))
+}
+
+// cursor over a single line of input
+pub struct Line<'a> {
+    // 1-based line number
+    pub number: usize,
+    pub text: &'a str,
+    // byte offset of the cursor
+    pos: usize,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: &'a str) -> Line<'a> {
+        Line { number, text, pos: 0 }
+    }
+
+    // the part of the line not consumed yet
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    pub fn at_end(&self) -> bool {
+        self.rest().is_empty()
+    }
+
+    // error at the cursor
+    pub fn error(&self, expected: &str) -> ParseError {
+        self.error_at(self.pos, expected)
+    }
+
+    // error at the given byte offset into the line,
+    // reporting the token found there: a run of letters and digits,
+    // or else a single character
+    pub fn error_at(&self, pos: usize, expected: &str) -> ParseError {
+        let rest = &self.text[pos..];
+        let found = match rest.chars().next() {
+            None => "end of line",
+            Some(c) if c.is_alphanumeric() => {
+                let len = rest.find(|c: char| !c.is_alphanumeric()).unwrap_or(rest.len());
+                &rest[..len]
+            }
+            Some(c) => &rest[..c.len_utf8()],
+        };
+        let column = self.text[..pos].chars().count() + 1;
+        ParseError::new(self.number, column, expected, found, self.text)
+    }
+
+    pub fn skip_spaces(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    // byte offset of the cursor, for use with error_at
+    pub fn pos(&self) -> usize {
+        self.pos
+    }
+
+    // next character, without skipping spaces
+    pub fn next_char(&mut self) -> Option<char> {
+        let c = self.rest().chars().next()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    // next character, which must be accepted by the predicate
+    pub fn char_matching(&mut self, expected: &str, accept: impl Fn(char) -> bool) -> Result<char, ParseError> {
+        match self.rest().chars().next() {
+            Some(c) if accept(c) => {
+                self.pos += c.len_utf8();
+                Ok(c)
+            }
+            _ => Err(self.error(expected)),
+        }
+    }
+
+    // next whitespace-delimited word
+    pub fn word(&mut self, expected: &str) -> Result<&'a str, ParseError> {
+        self.skip_spaces();
+        let rest = self.rest();
+        let len = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error(expected));
+        }
+        self.pos += len;
+        Ok(&rest[..len])
+    }
+
+    // next word, which must be exactly `keyword`
+    pub fn keyword(&mut self, keyword: &str) -> Result<(), ParseError> {
+        self.skip_spaces();
+        let start = self.pos;
+        let expected = format!("`{}`", keyword);
+        if self.word(&expected)? != keyword {
+            return Err(self.error_at(start, &expected));
+        }
+        Ok(())
+    }
+
+    // exact text at the cursor, without skipping spaces
+    pub fn literal(&mut self, literal: &str) -> Result<(), ParseError> {
+        if !self.rest().starts_with(literal) {
+            return Err(self.error(&format!("`{}`", literal)));
+        }
+        self.pos += literal.len();
+        Ok(())
+    }
+
+    // an optionally signed decimal number, skipping leading spaces
+    pub fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        self.skip_spaces();
+        let rest = self.rest();
+        let sign = if rest.starts_with('-') || rest.starts_with('+') { 1 } else { 0 };
+        let digits = rest[sign..].len() - rest[sign..].trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        if digits == 0 {
+            return Err(self.error("a number"));
+        }
+        let value = rest[..sign + digits]
+            .parse()
+            .map_err(|_| self.error("a number in range"))?;
+        self.pos += sign + digits;
+        Ok(value)
+    }
+
+    // nothing but spaces until the end of the line
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        self.skip_spaces();
+        if !self.at_end() {
+            return Err(self.error("end of line"));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+/*
+Recorded simulations, for stepping through a puzzle in the visualizer.
+
+Days which simulate something step by step can record the state after
+every step, and draw any recorded step as a frame: a picture made of
+characters, a caption saying what just happened, and optionally a point
+of interest to keep in view when the picture is bigger than the screen.
+*/
+
+use grid::Grid;
+
+use crate::{ParseError, Solution};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    pub caption: String,
+    pub picture: Grid<char>,
+    // (x, y) in the picture to keep in view
+    pub focus: Option<(usize, usize)>,
+}
+
+impl Frame {
+    pub fn new(caption: impl Into<String>, picture: Grid<char>) -> Frame {
+        Frame {
+            caption: caption.into(),
+            picture,
+            focus: None,
+        }
+    }
+
+    // picture from lines of text, padded with spaces into a rectangle
+    pub fn from_text(caption: impl Into<String>, text: &str) -> Frame {
+        let lines = text.lines().map(|line| line.chars().collect::<Vec<char>>()).collect::<Vec<_>>();
+        let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+        let picture = Grid::from_fn(width, lines.len(), |x, y| lines[y].get(x).copied().unwrap_or(' '));
+        Frame::new(caption, picture)
+    }
+
+    pub fn with_focus(mut self, focus: (usize, usize)) -> Frame {
+        self.focus = Some(focus);
+        self
+    }
+}
+
+pub trait Simulation: Solution {
+    type State;
+
+    // the state after each step of solving the given part of the puzzle,
+    // usually starting with the state before the first step
+    fn record(input: &Self::Input, part: u8) -> Vec<Self::State>;
+
+    // draw the state after the given step,
+    // which may also depend on the steps before it
+    fn frame(states: &[Self::State], step: usize) -> Frame;
+}
+
+// recorded states of a simulation, which can be drawn as frames
+pub struct Recording {
+    len: usize,
+    draw: Box<dyn Fn(usize) -> Frame>,
+}
+
+impl Recording {
+    // number of frames, counting the initial state
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn frame(&self, step: usize) -> Frame {
+        (self.draw)(step)
+    }
+}
+
+// parse the input and record the simulation of one part of the puzzle
+pub fn record<S: Simulation>(input: &str, part: u8) -> Result<Recording, ParseError>
+where
+    S::State: 'static,
+{
+    let input = S::parse(input)?;
+    let states = S::record(&input, part);
+    Ok(Recording {
+        len: states.len(),
+        draw: Box::new(move |step| S::frame(&states, step)),
+    })
+}
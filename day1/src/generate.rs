@@ -0,0 +1,27 @@
+// random calorie lists, one group of items per elf
+
+use common::generate::{Generator, Rng, StdRng};
+
+use crate::Day1;
+
+impl Generator for Day1 {
+    // number of elves (at least one, so that there is an elf to find)
+    const DEFAULT_SIZE: usize = 250;
+
+    fn generate(rng: &mut StdRng, size: usize) -> Vec<Vec<isize>> {
+        (0..size.max(1))
+            .map(|_| {
+                let items = rng.gen_range(1..=15);
+                (0..items).map(|_| rng.gen_range(1000..=60000)).collect()
+            })
+            .collect()
+    }
+
+    fn to_text(elves: &Vec<Vec<isize>>) -> String {
+        elves
+            .iter()
+            .map(|items| items.iter().map(|item| format!("{}\n", item)).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,167 @@
+/*
+Groups of numbers, one per line, separated by blank lines, read one
+line at a time so that only the group being read is kept. The lines
+come from text in memory, or from a reader so that the input need not
+be held at all.
+
+A blank line ends the group before it, so a blank line at the end of
+the input adds nothing. Blank lines with no group before them (at the
+start, or after another blank line) are empty groups, which are
+skipped, kept or taken as an error as asked. Lines may end in \r\n,
+and blank lines may hold spaces.
+
+Each group's total is checked as it is summed, and a number which
+would overflow it is an error at that number. Callers which only want
+the totals can leave the numbers themselves out.
+*/
+
+use std::io::BufRead;
+
+use common::parse::Line;
+use common::{LoadError, ParseError};
+
+// what to do with a group which has no numbers in it
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EmptyGroups {
+    #[default]
+    Skip,
+    Error,
+    Keep,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Group {
+    // line of the first number, or the blank line of an empty group
+    pub line: usize,
+    // empty when only the totals are wanted
+    pub items: Vec<isize>,
+    pub total: isize,
+}
+
+impl Group {
+    fn new(line: usize) -> Group {
+        Group { line, items: Vec::new(), total: 0 }
+    }
+}
+
+pub struct Groups<I> {
+    lines: I,
+    // number of the last line read
+    number: usize,
+    empty: EmptyGroups,
+    // whether to keep the numbers of each group, or only its total
+    keep_items: bool,
+    // name of the input, for errors read from a file
+    file: Option<String>,
+    // the group being read, if any
+    current: Option<Group>,
+    // set after the end of the input or an error
+    done: bool,
+}
+
+impl<I> Groups<I> {
+    fn new(lines: I, empty: EmptyGroups, file: Option<String>) -> Groups<I> {
+        Groups {
+            lines,
+            number: 0,
+            empty,
+            keep_items: true,
+            file,
+            current: None,
+            done: false,
+        }
+    }
+
+    // the same groups with only their totals, leaving their items empty
+    pub fn totals_only(mut self) -> Groups<I> {
+        self.keep_items = false;
+        self
+    }
+
+    // add the number on the line to the current group
+    fn item(&mut self, line: &mut Line) -> Result<(), ParseError> {
+        line.skip_spaces();
+        let start = line.pos();
+        let item = line.number::<isize>()?;
+        line.end()?;
+        let group = self.current.get_or_insert_with(|| Group::new(line.number));
+        group.total = group
+            .total
+            .checked_add(item)
+            .ok_or_else(|| line.error_at(start, "a number which keeps the total in range"))?;
+        if self.keep_items {
+            group.items.push(item);
+        }
+        Ok(())
+    }
+
+    // stop at an error, naming the file it is in
+    fn fail(&mut self, err: ParseError) -> ParseError {
+        self.done = true;
+        match &self.file {
+            Some(file) => err.in_file(file),
+            None => err,
+        }
+    }
+}
+
+// the groups in the text, in order
+pub fn groups(text: &str, empty: EmptyGroups) -> Groups<impl Iterator<Item = Result<&str, ParseError>>> {
+    Groups::new(text.lines().map(Ok), empty, None)
+}
+
+// the groups read from a reader, in order, with errors naming the input
+pub fn read_groups(
+    name: &str,
+    reader: impl BufRead,
+    empty: EmptyGroups,
+) -> Groups<impl Iterator<Item = Result<String, LoadError>>> {
+    let file = name.to_string();
+    let lines = reader.lines().map(move |line| line.map_err(|err| LoadError::Io(file.clone(), err)));
+    Groups::new(lines, empty, Some(name.to_string()))
+}
+
+impl<S, E, I> Iterator for Groups<I>
+where
+    S: AsRef<str>,
+    E: From<ParseError>,
+    I: Iterator<Item = Result<S, E>>,
+{
+    type Item = Result<Group, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            let text = match self.lines.next() {
+                Some(Ok(text)) => text,
+                Some(Err(err)) => {
+                    self.done = true;
+                    return Some(Err(err));
+                }
+                None => {
+                    self.done = true;
+                    return self.current.take().map(Ok);
+                }
+            };
+            self.number += 1;
+            let mut line = Line::new(self.number, text.as_ref());
+            if !line.is_empty() {
+                if let Err(err) = self.item(&mut line) {
+                    return Some(Err(self.fail(err).into()));
+                }
+                continue;
+            }
+            if let Some(group) = self.current.take() {
+                return Some(Ok(group));
+            }
+            match self.empty {
+                EmptyGroups::Skip => (),
+                EmptyGroups::Keep => return Some(Ok(Group::new(line.number))),
+                EmptyGroups::Error => {
+                    let err = line.error("a number");
+                    return Some(Err(self.fail(err).into()));
+                }
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,98 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use common::{LoadError, ParseError, Solution, SolveError, Source};
+
+mod generate;
+mod groups;
+mod stats;
+
+pub use groups::{groups, read_groups, EmptyGroups, Group, Groups};
+pub use stats::{Bucket, Stats, PERCENTILES};
+
+// read the calories carried by each elf: lists of numbers, one per line,
+// separated by blank lines, with empty lists dealt with as asked
+pub fn read_input(text: &str, empty: EmptyGroups) -> Result<Vec<Vec<isize>>, ParseError> {
+    groups(text, empty).map(|group| group.map(|group| group.items)).collect()
+}
+
+// compute the sum of a list of integers
+fn sum(list: &[isize]) -> isize {
+    list.iter().sum()
+}
+
+// the sums of the lists in the input text, read one line at a time
+// without keeping the lists, in the same order as read_input gives them
+pub fn totals(text: &str) -> impl Iterator<Item = Result<isize, ParseError>> + '_ {
+    groups(text, EmptyGroups::Skip).totals_only().map(|group| group.map(|group| group.total))
+}
+
+// the k largest totals with the 1-based index of the elf carrying each,
+// largest first, and of equal totals the elf which comes first.
+// Only the best k so far are kept, in a heap whose top is the worst of
+// them, so this takes O(n log k) time and O(k) space.
+pub fn top_k_totals(totals: impl IntoIterator<Item = isize>, k: usize) -> Vec<(usize, isize)> {
+    // ordered so that the greatest is the worst: smallest total, then latest elf
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    for (i, total) in totals.into_iter().enumerate() {
+        heap.push((Reverse(total), i + 1));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+    heap.into_sorted_vec().into_iter().map(|(Reverse(total), i)| (i, total)).collect()
+}
+
+// the k elves carrying the most calories, as (1-based index, total)
+pub fn top_k(elves: &[Vec<isize>], k: usize) -> Vec<(usize, isize)> {
+    top_k_totals(elves.iter().map(|list| sum(list)), k)
+}
+
+// the k largest totals straight from the input text, without reading
+// it into lists first
+pub fn top_k_text(text: &str, k: usize) -> Result<Vec<(usize, isize)>, ParseError> {
+    let mut error = None;
+    let top = top_k_totals(totals(text).map_while(|total| total.map_err(|err| error = Some(err)).ok()), k);
+    match error {
+        Some(err) => Err(err),
+        None => Ok(top),
+    }
+}
+
+// the calories carried by the k elves carrying the most, which is an
+// error rather than a panic if it is too many for an isize
+fn sum_top_k(elves: &[Vec<isize>], k: usize) -> Result<isize, SolveError> {
+    top_k(elves, k)
+        .iter()
+        .try_fold(0isize, |sum, &(_, total)| sum.checked_add(total))
+        .ok_or_else(|| SolveError::new(&format!("the calories of the top {} elves overflow", k)))
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<Vec<isize>>;
+    type Answer1 = Result<isize, SolveError>;
+    type Answer2 = Result<isize, SolveError>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input, EmptyGroups::Skip)
+    }
+
+    // read a line at a time, rather than the whole input first
+    fn load(source: &Source) -> Result<Self::Input, LoadError> {
+        read_groups(&source.name(), source.open()?, EmptyGroups::Skip)
+            .map(|group| group.map(|group| group.items))
+            .collect()
+    }
+
+    // calories carried by the elf carrying the most calories
+    fn part1(elves: &Self::Input) -> Result<isize, SolveError> {
+        sum_top_k(elves, 1)
+    }
+
+    // calories carried by the three elves carrying the most calories
+    fn part2(elves: &Self::Input) -> Result<isize, SolveError> {
+        sum_top_k(elves, 3)
+    }
+}
@@ -0,0 +1,262 @@
+/*
+The whole distribution of the calories the elves carry, rather than
+just the most. For the example, with 4 buckets:
+
+    elves                    5
+    items                   10
+    calories             55000
+    mean per elf       11000.0
+    median per elf     10000.0
+    p10                   4000
+    ...
+    p99                  24000
+    most                 24000  elf 4
+    least                 4000  elf 2
+    items per elf   1 to 3, 2.0 on average
+    largest item         10000  elf 5
+
+    histogram
+         4000 -      9000      2  ########################################
+         9001 -     14001      2  ########################################
+        14002 -     19002      0
+        19003 -     24000      1  ####################
+
+Percentiles are by nearest rank: the pth percentile is the smallest
+total which at least p% of the elves carry no more than. The histogram
+splits the range of totals into buckets of equal width. An elf's rank is
+1 for the elves carrying the most, and elves with equal totals share a
+rank.
+*/
+
+use common::json;
+
+use crate::sum;
+
+// the percentiles reported
+pub const PERCENTILES: [u32; 6] = [10, 25, 50, 75, 90, 99];
+
+// widest bar of the histogram, in characters
+const BAR: usize = 40;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bucket {
+    // inclusive range of totals
+    pub from: isize,
+    pub to: isize,
+    pub count: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    // total and number of items of each elf, by 0-based index
+    totals: Vec<isize>,
+    items: Vec<usize>,
+    // the totals, largest first
+    ranked: Vec<isize>,
+    // largest item and the 1-based index of its elf
+    largest_item: Option<(usize, isize)>,
+    buckets: usize,
+}
+
+impl Stats {
+    // statistics of at least one elf, with the histogram in the given number of buckets
+    pub fn new(elves: &[Vec<isize>], buckets: usize) -> Option<Stats> {
+        if elves.is_empty() {
+            return None;
+        }
+        let totals = elves.iter().map(|items| sum(items)).collect::<Vec<isize>>();
+        let mut ranked = totals.clone();
+        ranked.sort_unstable_by(|a, b| b.cmp(a));
+        let largest_item = elves
+            .iter()
+            .enumerate()
+            .flat_map(|(i, items)| items.iter().map(move |&item| (i + 1, item)))
+            .fold(None, |best: Option<(usize, isize)>, (i, item)| match best {
+                Some((_, most)) if most >= item => best,
+                _ => Some((i, item)),
+            });
+        Some(Stats {
+            items: elves.iter().map(|items| items.len()).collect(),
+            totals,
+            ranked,
+            largest_item,
+            buckets: buckets.max(1),
+        })
+    }
+
+    pub fn elves(&self) -> usize {
+        self.totals.len()
+    }
+
+    pub fn items(&self) -> usize {
+        self.items.iter().sum()
+    }
+
+    // all the calories carried
+    pub fn calories(&self) -> i128 {
+        self.totals.iter().map(|&total| total as i128).sum()
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.calories() as f64 / self.elves() as f64
+    }
+
+    pub fn median(&self) -> f64 {
+        let n = self.elves();
+        if n % 2 == 1 {
+            self.ranked[n / 2] as f64
+        } else {
+            (self.ranked[n / 2 - 1] as f64 + self.ranked[n / 2] as f64) / 2.0
+        }
+    }
+
+    // the total at the pth percentile, by nearest rank
+    pub fn percentile(&self, p: u32) -> isize {
+        let n = self.elves();
+        let rank = (p.min(100) as usize * n).div_ceil(100).max(1);
+        // ranked is largest first
+        self.ranked[n - rank]
+    }
+
+    // 1-based index and total of the elf carrying the most,
+    // the first of them if there are several
+    pub fn most(&self) -> (usize, isize) {
+        self.extreme(self.ranked[0])
+    }
+
+    pub fn least(&self) -> (usize, isize) {
+        self.extreme(self.ranked[self.elves() - 1])
+    }
+
+    fn extreme(&self, total: isize) -> (usize, isize) {
+        (self.totals.iter().position(|&t| t == total).unwrap_or(0) + 1, total)
+    }
+
+    // fewest, mean and most items carried by an elf
+    pub fn items_per_elf(&self) -> (usize, f64, usize) {
+        let min = self.items.iter().copied().min().unwrap_or(0);
+        let max = self.items.iter().copied().max().unwrap_or(0);
+        (min, self.items() as f64 / self.elves() as f64, max)
+    }
+
+    // the largest single item and the 1-based index of the first elf carrying it
+    pub fn largest_item(&self) -> Option<(usize, isize)> {
+        self.largest_item
+    }
+
+    // the number of elves whose totals fall in each bucket
+    pub fn histogram(&self) -> Vec<Bucket> {
+        let (least, most) = (self.ranked[self.elves() - 1] as i128, self.ranked[0] as i128);
+        let width = ((most - least + 1) as u128).div_ceil(self.buckets as u128) as i128;
+        let mut buckets = (0..self.buckets as i128)
+            .map(|i| least + i * width)
+            .take_while(|&from| from <= most)
+            .map(|from| Bucket {
+                from: from as isize,
+                to: (from + width - 1).min(most) as isize,
+                count: 0,
+            })
+            .collect::<Vec<Bucket>>();
+        for &total in &self.totals {
+            buckets[((total as i128 - least) / width) as usize].count += 1;
+        }
+        buckets
+    }
+
+    // rank of the elf with the given 1-based index, if there is one
+    pub fn rank(&self, elf: usize) -> Option<usize> {
+        let total = *self.totals.get(elf.checked_sub(1)?)?;
+        Some(self.ranked.partition_point(|&t| t > total) + 1)
+    }
+
+    // total and number of items of the elf with the given 1-based index
+    pub fn elf(&self, elf: usize) -> Option<(isize, usize)> {
+        let i = elf.checked_sub(1)?;
+        Some((*self.totals.get(i)?, self.items[i]))
+    }
+
+    // the statistics as a table of text, ending in the histogram
+    pub fn table(&self) -> String {
+        let row = |label: &str, value: String| format!("{:<16}{:>10}\n", label, value);
+        let with_elf = |label: &str, (elf, total): (usize, isize)| format!("{:<16}{:>10}  elf {}\n", label, total, elf);
+        let mut text = row("elves", self.elves().to_string());
+        text += &row("items", self.items().to_string());
+        text += &row("calories", self.calories().to_string());
+        text += &row("mean per elf", format!("{:.1}", self.mean()));
+        text += &row("median per elf", format!("{:.1}", self.median()));
+        for p in PERCENTILES {
+            text += &row(&format!("p{}", p), self.percentile(p).to_string());
+        }
+        text += &with_elf("most", self.most());
+        text += &with_elf("least", self.least());
+        let (fewest, mean, most) = self.items_per_elf();
+        text += &format!("{:<16}{} to {}, {:.1} on average\n", "items per elf", fewest, most, mean);
+        if let Some(item) = self.largest_item() {
+            text += &with_elf("largest item", item);
+        }
+        text += "\nhistogram\n";
+        let histogram = self.histogram();
+        let widest = histogram.iter().map(|bucket| bucket.count).max().unwrap_or(1);
+        for bucket in histogram {
+            let bar = "#".repeat((bucket.count * BAR).div_ceil(widest));
+            let line = format!("{:>9} - {:>9}  {:>5}  {}", bucket.from, bucket.to, bucket.count, bar);
+            text += line.trim_end();
+            text.push('\n');
+        }
+        text
+    }
+
+    // the statistics as one JSON object, with the given elf's rank if any
+    pub fn to_json(&self, elf: Option<usize>) -> String {
+        let with_elf = |(elf, total): (usize, isize)| {
+            json::object(&[("elf", elf.to_string()), ("total", total.to_string())])
+        };
+        let (fewest, mean, most) = self.items_per_elf();
+        let names = PERCENTILES.map(|p| format!("p{}", p));
+        let percentiles = PERCENTILES
+            .iter()
+            .zip(&names)
+            .map(|(&p, name)| (name.as_str(), self.percentile(p).to_string()))
+            .collect::<Vec<(&str, String)>>();
+        let histogram = self.histogram().into_iter().map(|bucket| {
+            json::object(&[
+                ("from", bucket.from.to_string()),
+                ("to", bucket.to.to_string()),
+                ("count", bucket.count.to_string()),
+            ])
+        });
+        let mut fields = vec![
+            ("elves", self.elves().to_string()),
+            ("items", self.items().to_string()),
+            ("calories", self.calories().to_string()),
+            ("mean", self.mean().to_string()),
+            ("median", self.median().to_string()),
+            ("percentiles", json::object(&percentiles)),
+            ("most", with_elf(self.most())),
+            ("least", with_elf(self.least())),
+            (
+                "items_per_elf",
+                json::object(&[("min", fewest.to_string()), ("mean", mean.to_string()), ("max", most.to_string())]),
+            ),
+        ];
+        if let Some((elf, item)) = self.largest_item() {
+            fields.push((
+                "largest_item",
+                json::object(&[("elf", elf.to_string()), ("calories", item.to_string())]),
+            ));
+        }
+        fields.push(("histogram", json::array(histogram)));
+        if let Some(elf) = elf {
+            if let (Some((total, items)), Some(rank)) = (self.elf(elf), self.rank(elf)) {
+                let elf = json::object(&[
+                    ("elf", elf.to_string()),
+                    ("total", total.to_string()),
+                    ("items", items.to_string()),
+                    ("rank", rank.to_string()),
+                ]);
+                fields.push(("elf", elf));
+            }
+        }
+        json::object(&fields)
+    }
+}
@@ -0,0 +1,78 @@
+use common::{LoadError, Solution, SolveError};
+use day1::{groups, read_groups, read_input, Day1, EmptyGroups, Group};
+
+fn error_at(text: &str, empty: EmptyGroups) -> (usize, usize) {
+    let err = read_input(text, empty).unwrap_err();
+    (err.line, err.column)
+}
+
+#[test]
+fn empty_groups_follow_the_policy() {
+    let text = "\n1\n2\n\n\n3\n\n";
+    assert_eq!(read_input(text, EmptyGroups::Skip).unwrap(), vec![vec![1, 2], vec![3]]);
+    assert_eq!(
+        read_input(text, EmptyGroups::Keep).unwrap(),
+        vec![vec![], vec![1, 2], vec![], vec![3]]
+    );
+    assert_eq!(error_at(text, EmptyGroups::Error), (1, 1));
+    assert_eq!(error_at("1\n\n  \n2\n", EmptyGroups::Error), (3, 1));
+    // a blank line at the end only ends the last group
+    assert_eq!(read_input("1\n\n", EmptyGroups::Error).unwrap(), vec![vec![1]]);
+    assert_eq!(read_input("", EmptyGroups::Keep).unwrap(), Vec::<Vec<isize>>::new());
+}
+
+#[test]
+fn groups_know_where_they_start() {
+    let found = groups("\r\n 10 \r\n20\r\n\r\n30", EmptyGroups::Keep).collect::<Result<Vec<Group>, _>>().unwrap();
+    assert_eq!(
+        found,
+        vec![
+            Group { line: 1, items: vec![], total: 0 },
+            Group { line: 2, items: vec![10, 20], total: 30 },
+            Group { line: 5, items: vec![30], total: 30 },
+        ]
+    );
+}
+
+#[test]
+fn bad_lines_are_errors_where_they_go_wrong() {
+    assert_eq!(error_at("100\n2oo\n", EmptyGroups::Skip), (2, 2));
+    assert_eq!(error_at("100\n\nabc\n", EmptyGroups::Skip), (3, 1));
+    assert_eq!(error_at("1\n99999999999999999999\n", EmptyGroups::Skip), (2, 1));
+    let big = format!("1\n{}\n", isize::MAX);
+    assert_eq!(error_at(&big, EmptyGroups::Skip), (2, 1));
+    assert_eq!(read_input(&format!("\n{}\n", isize::MAX), EmptyGroups::Skip).unwrap(), vec![vec![isize::MAX]]);
+}
+
+#[test]
+fn totals_too_big_to_add_up_are_errors() {
+    let elves = Day1::parse(&format!("{}\n\n{}\n", isize::MAX, isize::MAX)).unwrap();
+    assert_eq!(Day1::part1(&elves), Ok(isize::MAX));
+    assert_eq!(Day1::part2(&elves), Err(SolveError::new("the calories of the top 3 elves overflow")));
+}
+
+#[test]
+fn totals_alone_leave_out_the_items() {
+    let found = groups("1\n2\n\n3\n", EmptyGroups::Skip).totals_only().collect::<Result<Vec<Group>, _>>().unwrap();
+    assert_eq!(
+        found,
+        vec![Group { line: 1, items: vec![], total: 3 }, Group { line: 4, items: vec![], total: 3 }]
+    );
+}
+
+#[test]
+fn readers_give_the_same_groups() {
+    let text = "\r\n 10 \r\n20\r\n\r\n30";
+    let read = read_groups("day1/input", text.as_bytes(), EmptyGroups::Keep).collect::<Result<Vec<Group>, _>>().unwrap();
+    assert_eq!(read, groups(text, EmptyGroups::Keep).collect::<Result<Vec<Group>, _>>().unwrap());
+
+    let read = read_groups("day1/input", &b"1\n\nx\n"[..], EmptyGroups::Skip).collect::<Result<Vec<Group>, _>>();
+    let Err(LoadError::Parse(err)) = read else {
+        panic!("expected a parse error");
+    };
+    assert_eq!((err.file.as_deref(), err.line, err.column), (Some("day1/input"), 3, 1));
+    // a line which is not UTF-8 cannot be read
+    let mut bad = read_groups("day1/input", &b"1\n\xff\n"[..], EmptyGroups::Skip);
+    assert!(matches!(bad.next(), Some(Err(LoadError::Io(name, _))) if name == "day1/input"));
+    assert!(bad.next().is_none());
+}
@@ -0,0 +1,51 @@
+use common::Solution;
+use day1::{Bucket, Day1, Stats};
+
+const EXAMPLE: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+fn example() -> Stats {
+    Stats::new(&Day1::parse(EXAMPLE).unwrap(), 4).unwrap()
+}
+
+#[test]
+fn the_distribution_of_the_example() {
+    let stats = example();
+    assert_eq!((stats.elves(), stats.items(), stats.calories()), (5, 10, 55000));
+    assert_eq!((stats.mean(), stats.median()), (11000.0, 10000.0));
+    let percentiles = [0, 10, 20, 21, 50, 80, 81, 100].map(|p| stats.percentile(p));
+    assert_eq!(percentiles, [4000, 4000, 4000, 6000, 10000, 11000, 24000, 24000]);
+    assert_eq!((stats.most(), stats.least()), ((4, 24000), (2, 4000)));
+    assert_eq!(stats.items_per_elf(), (1, 2.0, 3));
+    assert_eq!(stats.largest_item(), Some((5, 10000)));
+    let bucket = |from, to, count| Bucket { from, to, count };
+    assert_eq!(
+        stats.histogram(),
+        vec![bucket(4000, 9000, 2), bucket(9001, 14001, 2), bucket(14002, 19002, 0), bucket(19003, 24000, 1)]
+    );
+    assert_eq!(Stats::new(&[], 4), None);
+}
+
+#[test]
+fn equal_totals_share_a_rank() {
+    let stats = Stats::new(&[vec![5], vec![7], vec![2, 3], vec![1]], 10).unwrap();
+    assert_eq!((1..=4).map(|elf| stats.rank(elf).unwrap()).collect::<Vec<usize>>(), vec![2, 1, 2, 4]);
+    assert_eq!((stats.rank(0), stats.rank(5)), (None, None));
+    assert_eq!(stats.elf(3), Some((5, 2)));
+    assert_eq!(stats.median(), 5.0);
+    // one bucket per total, as the range is narrower than the buckets asked for
+    assert_eq!(stats.histogram().len(), 7);
+}
+
+#[test]
+fn reports_as_text_and_json() {
+    let stats = example();
+    let table = stats.table();
+    assert!(table.starts_with("elves                    5\nitems                   10\n"));
+    assert!(table.contains("\nmost                 24000  elf 4\n"));
+    assert!(table.ends_with("    14002 -     19002      0\n    19003 -     24000      1  ####################\n"));
+    let json = stats.to_json(Some(3));
+    assert!(json.starts_with(r#"{"elves":5,"items":10,"calories":55000,"mean":11000,"median":10000,"#));
+    assert!(json.contains(r#""percentiles":{"p10":4000,"p25":6000,"p50":10000,"p75":11000,"p90":24000,"p99":24000}"#));
+    assert!(json.ends_with(r#","elf":{"elf":3,"total":11000,"items":2,"rank":2}}"#));
+    assert!(!stats.to_json(None).contains(r#""elf":{"#));
+}
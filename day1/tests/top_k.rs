@@ -0,0 +1,28 @@
+use common::Solution;
+use day1::{top_k, top_k_text, top_k_totals, totals, Day1};
+
+const EXAMPLE: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+#[test]
+fn top_k_finds_the_largest_totals_first() {
+    let elves = Day1::parse(EXAMPLE).unwrap();
+    assert_eq!(top_k(&elves, 3), vec![(4, 24000), (3, 11000), (5, 10000)]);
+    assert_eq!(top_k(&elves, 1), vec![(4, 24000)]);
+    assert_eq!(top_k(&elves, 0), vec![]);
+    assert_eq!(top_k(&elves, 9).len(), 5);
+}
+
+#[test]
+fn equal_totals_go_to_the_first_elf() {
+    assert_eq!(top_k_totals([5, 7, 5, 7, 5], 3), vec![(2, 7), (4, 7), (1, 5)]);
+    assert_eq!(top_k_totals([3, 3, 3, 3], 2), vec![(1, 3), (2, 3)]);
+}
+
+#[test]
+fn streaming_reads_the_same_totals() {
+    let streamed = totals(EXAMPLE).collect::<Result<Vec<isize>, _>>().unwrap();
+    assert_eq!(streamed, vec![6000, 4000, 11000, 24000, 10000]);
+    assert_eq!(top_k_text(EXAMPLE, 3).unwrap(), top_k(&Day1::parse(EXAMPLE).unwrap(), 3));
+    let err = top_k_text("100\n\n2oo\n", 3).unwrap_err();
+    assert_eq!((err.line, err.column), (3, 2));
+}
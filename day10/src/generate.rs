@@ -0,0 +1,34 @@
+// random CPU programs
+
+use common::generate::{Generator, Rng, StdRng};
+
+use crate::{Day10, Instruction, Program};
+
+impl Generator for Day10 {
+    // number of instructions
+    const DEFAULT_SIZE: usize = 144;
+
+    fn generate(rng: &mut StdRng, size: usize) -> Program {
+        let mut program = Program::new();
+        for _ in 0..size {
+            let instruction = if rng.gen_bool(0.3) {
+                Instruction::Noop
+            } else {
+                Instruction::AddX(rng.gen_range(-40..=40))
+            };
+            program.instructions.push(instruction);
+        }
+        program
+    }
+
+    fn to_text(program: &Program) -> String {
+        program
+            .instructions
+            .iter()
+            .map(|instruction| match instruction {
+                Instruction::AddX(arg) => format!("addx {}\n", arg),
+                Instruction::Noop => "noop\n".to_string(),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,228 @@
+use common::log::{self, Level};
+use common::parse::lines;
+use common::{trace, verbose, ParseError, Solution, SolveError};
+use grid::Grid;
+
+mod generate;
+mod simulate;
+
+// 40x6 screen
+const CRT_WIDTH: usize = 40;
+const CRT_HEIGHT: usize = 6;
+
+#[derive(Clone)]
+#[derive(Debug, PartialEq)]
+enum Instruction {
+    AddX(i32),
+    Noop,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Program {
+    instructions: Vec<Instruction>,
+    pc: usize,
+    acc: i32,
+    rt: i32,
+    total_signal: i32,
+    crt: Grid<char>,
+    // every cycle run so far, when recording for the visualizer
+    history: Option<Vec<simulate::Cycle>>,
+}
+
+impl Program {
+    fn new() -> Program {
+        Program {
+            instructions: Vec::new(),
+            pc: 0,
+            acc: 1,
+            rt: 0,
+            total_signal: 0,
+            crt: Grid::new(CRT_WIDTH, CRT_HEIGHT, '.'),
+            history: None,
+        }
+    }
+
+    /*
+    read input text
+
+    Example:
+
+        addx 15
+        addx -11
+        addx 6
+        noop
+        addx 5
+        addx -1
+    */
+    fn load(&mut self, text: &str) -> Result<(), ParseError> {
+        for mut line in lines(text) {
+            line.skip_spaces();
+            let start = line.pos();
+            let op = line.word("an opcode")?;
+            match op {
+                "addx" => {
+                    let arg = line.number()?;
+                    self.instructions.push(Instruction::AddX(arg));
+                }
+                "noop" => {
+                    self.instructions.push(Instruction::Noop);
+                }
+                _ => return Err(line.error_at(start, "an opcode `addx` or `noop`")),
+            }
+            line.end()?;
+        }
+        Ok(())
+    }
+
+    // run n cycles, failing if a signal strength or their sum overflows
+    fn advance_runtime(&mut self, n: i32) -> Result<(), SolveError> {
+        const MEASUREMENT_TIMES : [i32; 6] = [20, 60, 100, 140, 180, 220];
+        for i in 0..n {
+            // update crt
+            // we draw to the rt'th pixel (0-based), scanning from left to right, top to bottom
+            // we draw a pixel if the 3-pixel sprite is visibile from the current horizontal position
+            // The sprite is centered on the acc value.
+            // Programs running past the last row draw nothing more.
+            let horiz = self.rt % CRT_WIDTH as i32;
+            let vert = self.rt / CRT_WIDTH as i32;
+            let draw = vert < CRT_HEIGHT as i32 && (horiz as i64 - self.acc as i64).abs() < 2;
+            if draw {
+                self.crt[(horiz as usize, vert as usize)] = '#';
+            }
+
+            // dump state
+            /* example:
+            Sprite position: ###.....................................
+
+            Start cycle   1: begin executing addx 15
+            During cycle  1: CRT draws pixel in position 0
+            Current CRT row: #
+
+            During cycle  2: CRT draws pixel in position 1
+            Current CRT row: ##
+            End of cycle  2: finish executing addx 15 (Register X is now 16)
+            Sprite position: ...............###......................
+
+            Start cycle   3: begin executing addx -11
+            During cycle  3: CRT draws pixel in position 2
+            Current CRT row: ##.
+
+            During cycle  4: CRT draws pixel in position 3
+            Current CRT row: ##..
+            End of cycle  4: finish executing addx -11 (Register X is now 5)
+            Sprite position: ....###.................................
+
+            Start cycle   5: begin executing addx 6
+            During cycle  5: CRT draws pixel in position 4
+            Current CRT row: ##..#
+            */
+            if log::enabled(Level::Trace) {
+                let mut sprite = String::new();
+                for i in 0..CRT_WIDTH as i32 {
+                    if (i as i64 - self.acc as i64).abs() < 2 {
+                        sprite.push('#');
+                    } else {
+                        sprite.push('.');
+                    }
+                }
+                if self.rt == 1 {
+                    trace!("Sprite position: {}", sprite);
+                    trace!();
+                }
+                trace!();
+                if i == 0 {
+                    trace!("Start cycle {:3}: begin executing {:?}", self.rt, self.instructions[self.pc]);
+                }
+                if draw {
+                    trace!("During cycle {:3}: CRT draws pixel in position {}", self.rt, horiz);
+                    trace!("Current CRT row: {}", self.crt.row(vert as usize).iter().collect::<String>());
+                    trace!();
+                }
+                if i == n - 1 {
+                    trace!("End of cycle {:3}: finish executing {:?}", self.rt, self.instructions[self.pc]);
+                    trace!("Sprite position: {}", sprite);
+                    trace!();
+                }
+            }
+
+            // advance runtime
+            self.rt += 1;
+            if let Some(history) = &mut self.history {
+                history.push(simulate::Cycle {
+                    cycle: self.rt,
+                    x: self.acc,
+                    instruction: self.instructions[self.pc].clone(),
+                    crt: self.crt.clone(),
+                });
+            }
+
+            // compute signal strength
+            if MEASUREMENT_TIMES.contains(&self.rt) {
+                // compute signal strength as rt * acc:
+                let signal = self.rt.checked_mul(self.acc);
+                verbose!("rt={} acc={} signal={:?}", self.rt, self.acc, signal);
+                self.total_signal = signal
+                    .and_then(|signal| self.total_signal.checked_add(signal))
+                    .ok_or_else(|| SolveError::new(&format!("signal strength overflowed in cycle {}", self.rt)))?;
+            }
+        }
+        Ok(())
+    }
+
+    // run the program to the end, failing if the X register overflows
+    fn run(&mut self) -> Result<(), SolveError> {
+        loop {
+            if self.pc >= self.instructions.len() {
+                break;
+            }
+            let instruction = self.instructions[self.pc].clone();
+            match instruction {
+                Instruction::AddX(arg) => {
+                    self.advance_runtime(2)?;
+                    self.acc = self.acc.checked_add(arg).ok_or_else(|| {
+                        SolveError::new(&format!("register X overflowed at instruction {}", self.pc + 1))
+                    })?;
+                    self.pc += 1;
+                }
+                Instruction::Noop => {
+                    self.advance_runtime(1)?;
+                    self.pc += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // render the CRT as one line of text per row
+    fn render_crt(&self) -> String {
+        self.crt.render(|&pixel| pixel)
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Program;
+    type Answer1 = Result<i32, SolveError>;
+    type Answer2 = Result<String, SolveError>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut program = Program::new();
+        program.load(input)?;
+        Ok(program)
+    }
+
+    // sum of the signal strengths at the measurement times
+    fn part1(program: &Self::Input) -> Result<i32, SolveError> {
+        let mut program = program.clone();
+        program.run()?;
+        Ok(program.total_signal)
+    }
+
+    // image drawn on the CRT
+    fn part2(program: &Self::Input) -> Result<String, SolveError> {
+        let mut program = program.clone();
+        program.run()?;
+        Ok(program.render_crt())
+    }
+}
@@ -0,0 +1,50 @@
+// the CRT after each cycle, for the visualizer
+
+use common::simulate::{Frame, Simulation};
+use grid::Grid;
+
+use crate::{Day10, Instruction, Program, CRT_HEIGHT, CRT_WIDTH};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cycle {
+    // 1-based number of the cycle just finished
+    pub(crate) cycle: i32,
+    // value of the X register during the cycle
+    pub(crate) x: i32,
+    pub(crate) instruction: Instruction,
+    pub(crate) crt: Grid<char>,
+}
+
+impl Simulation for Day10 {
+    type State = Cycle;
+
+    // both parts run the same program, so both record the same cycles
+    fn record(program: &Program, _part: u8) -> Vec<Cycle> {
+        let mut program = program.clone();
+        program.history = Some(Vec::new());
+        // an overflow ends the recording where it happened
+        let _ = program.run();
+        program.history.unwrap()
+    }
+
+    fn frame(cycles: &[Cycle], step: usize) -> Frame {
+        let Some(cycle) = cycles.get(step) else {
+            // a program without instructions never runs a cycle
+            return Frame::new("no cycles", Grid::new(CRT_WIDTH, CRT_HEIGHT, '.'));
+        };
+        let instruction = match cycle.instruction {
+            Instruction::AddX(arg) => format!("addx {}", arg),
+            Instruction::Noop => "noop".to_string(),
+        };
+        let caption = format!("cycle {}: {}, X = {}", cycle.cycle, instruction, cycle.x);
+        // the CRT, with the sprite position below it
+        let sprite = (0..CRT_WIDTH as i32)
+            .map(|i| if (i - cycle.x).abs() < 2 { '#' } else { '.' })
+            .collect::<String>();
+        let text = format!("{}\n\n{}", cycle.crt.render(|&pixel| pixel), sprite);
+        // keep the pixel just drawn in view
+        let pixel = (cycle.cycle - 1) as usize;
+        let focus = (pixel % CRT_WIDTH, (pixel / CRT_WIDTH).min(CRT_HEIGHT - 1));
+        Frame::from_text(caption, &text).with_focus(focus)
+    }
+}
@@ -0,0 +1,23 @@
+use common::{Solution, SolveError};
+use day10::Day10;
+
+fn run(text: &str) -> (Result<i32, SolveError>, Result<String, SolveError>) {
+    let program = Day10::parse(text).unwrap();
+    (Day10::part1(&program), Day10::part2(&program))
+}
+
+#[test]
+fn overflows_are_errors() {
+    let overflowed = SolveError::new("register X overflowed at instruction 1");
+    let (signal, crt) = run("addx 2147483647\naddx 1\n");
+    assert_eq!((signal.unwrap_err(), crt.unwrap_err()), (overflowed.clone(), overflowed));
+    let signal = format!("addx 2000000000\n{}", "noop\n".repeat(20));
+    assert_eq!(run(&signal).0, Err(SolveError::new("signal strength overflowed in cycle 20")));
+}
+
+#[test]
+fn the_sprite_can_be_anywhere() {
+    let (signal, crt) = run("addx -2147483647\naddx -2\nnoop\n");
+    assert_eq!(signal, Ok(0));
+    assert!(crt.unwrap().starts_with("##..."));
+}
@@ -0,0 +1,76 @@
+// random monkeys, shaped like the real puzzles: mostly additions,
+// a few multiplications, small prime divisors and throws to other monkeys.
+// Monkeys whose worry levels overflow within the 20 turns of part 1
+// are thrown away and generated again.
+
+use common::generate::{Generator, Rng, SliceRandom, StdRng};
+
+use crate::{Day11, Monkey, MonkeyBusiness, Operation};
+
+// divisors are kept small so that worry levels modulo their least common
+// multiple can still be squared without overflow
+const DIVISORS: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+impl Generator for Day11 {
+    // number of monkeys (at least two)
+    const DEFAULT_SIZE: usize = 8;
+
+    fn generate(rng: &mut StdRng, size: usize) -> MonkeyBusiness {
+        loop {
+            let monkey_business = generate_monkeys(rng, size.max(2));
+            if monkey_business.clone().run(20, true).is_ok() {
+                return monkey_business;
+            }
+        }
+    }
+
+    fn to_text(monkey_business: &MonkeyBusiness) -> String {
+        monkey_business
+            .monkeys
+            .iter()
+            .map(|monkey| {
+                let items = monkey.items.iter().map(|item| item.to_string()).collect::<Vec<String>>();
+                let operation = match monkey.operation {
+                    Operation::Add(n) => format!("old + {}", n),
+                    Operation::Multiply(n) => format!("old * {}", n),
+                    Operation::Double => "old + old".to_string(),
+                    Operation::Square => "old * old".to_string(),
+                };
+                format!(
+                    "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                    monkey.id,
+                    items.join(", "),
+                    operation,
+                    monkey.test_divisor,
+                    monkey.throw_to.0,
+                    monkey.throw_to.1
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+// one attempt at generating the monkeys
+fn generate_monkeys(rng: &mut StdRng, num_monkeys: usize) -> MonkeyBusiness {
+    let mut monkey_business = MonkeyBusiness::new();
+    for id in 0..num_monkeys {
+        let num_items = rng.gen_range(1..=8);
+        let items = (0..num_items).map(|_| rng.gen_range(50..=99)).collect();
+        let operation = match rng.gen_range(0..8) {
+            0 => Operation::Square,
+            1 => Operation::Double,
+            2 | 3 => Operation::Multiply(rng.gen_range(2..=19)),
+            _ => Operation::Add(rng.gen_range(1..=8)),
+        };
+        let test_divisor = *DIVISORS.choose(rng).unwrap();
+        // two different monkeys, neither of them this one
+        let mut others = (0..num_monkeys).filter(|&other| other != id).collect::<Vec<usize>>();
+        others.shuffle(rng);
+        let if_false = if others.len() > 1 { others[1] } else { others[0] };
+        monkey_business
+            .monkeys
+            .push(Monkey::new(id, items, operation, test_divisor, (others[0], if_false)));
+    }
+    monkey_business
+}
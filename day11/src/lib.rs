@@ -0,0 +1,349 @@
+use common::log::{self, Level};
+use common::parse::{lines, Line};
+use common::{trace, verbose, ParseError, Solution, SolveError};
+
+mod generate;
+mod simulate;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Operation {
+    Add(u64),
+    Multiply(u64),
+    Double,
+    Square,
+}
+
+impl Operation {
+    // new worry level, or None on overflow
+    fn apply(&self, old: u64) -> Option<u64> {
+        match *self {
+            Operation::Add(n) => old.checked_add(n),
+            Operation::Multiply(n) => old.checked_mul(n),
+            Operation::Double => old.checked_add(old),
+            Operation::Square => old.checked_mul(old),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Monkey {
+    id: usize,
+    items: Vec<u64>,
+    operation: Operation,
+    test_divisor: u64,
+    // number of items this monkey has inspected
+    inspections: usize,
+    // if dividible, throw_to[0], else throw_to[1]
+    throw_to: (usize, usize),
+}
+
+impl Monkey {
+    fn new(id : usize, items: Vec<u64>, operation: Operation, test_divisor: u64, throw_to: (usize, usize)) -> Monkey {
+        Monkey {
+            id,
+            items,
+            operation,
+            test_divisor,
+            inspections: 0,
+            throw_to,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MonkeyBusiness {
+    monkeys: Vec<Monkey>,
+    // snapshots taken so far, when recording for the visualizer
+    history: Option<simulate::History>,
+}
+
+impl MonkeyBusiness {
+    fn new() -> MonkeyBusiness {
+        MonkeyBusiness {
+            monkeys: Vec::new(),
+            history: None,
+        }
+    }
+
+    /*
+    Example input:
+
+    Monkey 0:
+    Starting items: 79, 98
+    Operation: new = old * 19
+    Test: divisible by 23
+        If true: throw to monkey 2
+        If false: throw to monkey 3
+
+    Monkey 1:
+    Starting items: 54, 65, 75, 74
+    Operation: new = old + 6
+    Test: divisible by 19
+        If true: throw to monkey 2
+        If false: throw to monkey 0
+
+    Monkey 2:
+    Starting items: 79, 60, 97
+    Operation: new = old * old
+    Test: divisible by 13
+        If true: throw to monkey 1
+        If false: throw to monkey 3
+
+    Monkey 3:
+    Starting items: 74
+    Operation: new = old + 3
+    Test: divisible by 17
+        If true: throw to monkey 0
+        If false: throw to monkey 1
+    */
+
+    fn load(&mut self, text: &str) -> Result<(), ParseError> {
+        // parse input text monkey-by-monkey, adding them to MonkeyBusiness.
+        // Monkeys may throw to monkeys defined later on, so the throw_to
+        // fields are only checked once all monkeys have been read.
+        let mut lines = lines(text).peekable();
+        let mut num_lines = 0;
+        let mut throws = Vec::new();
+        loop {
+            // blank lines separate monkeys
+            while lines.next_if(|line| line.is_empty()).is_some() {}
+            if lines.peek().is_none() {
+                break;
+            }
+            let id = self.monkeys.len();
+            let mut next_line = |expected: &str| {
+                let line = lines.next().ok_or_else(|| ParseError::end_of_input(num_lines, expected))?;
+                num_lines = line.number;
+                Ok(line)
+            };
+
+            // "Monkey 0:"
+            let mut line = next_line("`Monkey`")?;
+            line.keyword("Monkey")?;
+            line.skip_spaces();
+            let start = line.pos();
+            if line.number::<usize>()? != id {
+                return Err(line.error_at(start, &format!("monkey {}", id)));
+            }
+            line.literal(":")?;
+            line.end()?;
+
+            // "Starting items: 79, 98"
+            let mut line = next_line("`Starting`")?;
+            line.keyword("Starting")?;
+            line.keyword("items:")?;
+            let mut items = Vec::new();
+            line.skip_spaces();
+            while !line.at_end() {
+                if !items.is_empty() {
+                    line.literal(",")?;
+                }
+                items.push(line.number()?);
+                line.skip_spaces();
+            }
+
+            // "Operation: new = old * 19"
+            let mut line = next_line("`Operation:`")?;
+            line.keyword("Operation:")?;
+            line.keyword("new")?;
+            line.keyword("=")?;
+            line.keyword("old")?;
+            line.skip_spaces();
+            let operator = line.char_matching("an operator `*` or `+`", |c| c == '*' || c == '+')?;
+            line.skip_spaces();
+            // right operand is either a number or "old"
+            // considering the case where it is "old", which squares or doubles
+            let operation = if line.rest().starts_with("old") {
+                line.keyword("old")?;
+                match operator {
+                    '*' => Operation::Square,
+                    _ => Operation::Double,
+                }
+            } else {
+                let n = line.number()?;
+                match operator {
+                    '*' => Operation::Multiply(n),
+                    _ => Operation::Add(n),
+                }
+            };
+            line.end()?;
+
+            // "Test: divisible by 23"
+            let mut line = next_line("`Test:`")?;
+            line.keyword("Test:")?;
+            line.keyword("divisible")?;
+            line.keyword("by")?;
+            line.skip_spaces();
+            let start = line.pos();
+            let test_divisor = line.number()?;
+            if test_divisor == 0 {
+                return Err(line.error_at(start, "a divisor of at least 1"));
+            }
+            line.end()?;
+
+            // "If true: throw to monkey 2"
+            // "If false: throw to monkey 3"
+            let if_true = read_throw(&mut next_line("`If`")?, "true:", &mut throws)?;
+            let if_false = read_throw(&mut next_line("`If`")?, "false:", &mut throws)?;
+
+            self.monkeys.push(Monkey::new(id, items, operation, test_divisor, (if_true, if_false)));
+        }
+        // now that all monkeys are known, check the throw_to fields
+        for (monkey_id, mut error) in throws {
+            if monkey_id >= self.monkeys.len() {
+                error.expected = format!("a monkey from 0 to {}", self.monkeys.len() - 1);
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
+    // play the given number of turns.
+    // With relief, worry levels are divided by three after each inspection.
+    // Without it they grow without bound, so we keep them modulo the least
+    // common multiple of all test divisors, which does not change the outcome
+    // of any test. If that multiple does not fit in a u64, no worry level
+    // can reach it, and they are left as they are.
+    // Stops early with an error if a worry level no longer fits in a u64,
+    // as nothing bounds the starting items or the operations.
+    fn run(&mut self, num_turns: usize, relief: bool) -> Result<(), SolveError> {
+        let modulus = self
+            .monkeys
+            .iter()
+            .try_fold(1, |modulus, monkey| lcm(modulus, monkey.test_divisor));
+        // iterate over monkeys, each monkey gets a turn
+        for turn in 0..num_turns {
+            if log::enabled(Level::Trace) {
+                trace!("=== Turn {} ===", turn + 1);
+                self.print_items();
+            }
+            for i in 0..self.monkeys.len() {
+                // process all items
+                let items = std::mem::take(&mut self.monkeys[i].items);
+                for item in items {
+                    let monkey = &self.monkeys[i];
+                    // apply operation
+                    let mut new_item = monkey
+                        .operation
+                        .apply(item)
+                        .ok_or_else(|| {
+                            let message = format!("worry level overflowed in turn {}, inspected by monkey {}", turn + 1, i);
+                            SolveError::new(&message)
+                        })?;
+                    if relief {
+                        new_item /= 3;
+                    } else if let Some(modulus) = modulus {
+                        new_item %= modulus;
+                    }
+                    // test
+                    let throw_to = if new_item % monkey.test_divisor == 0 {
+                        monkey.throw_to.0
+                    } else {
+                        monkey.throw_to.1
+                    };
+                    // throw by pushing to back of queue for monkey id "throw_to"
+                    self.monkeys[i].inspections += 1;
+                    self.monkeys[throw_to].items.push(new_item);
+                    if let Some(history) = &mut self.history {
+                        if history.every_throw {
+                            let throw = simulate::Throw { from: i, to: throw_to, item: new_item };
+                            history.snapshots.push(simulate::Snapshot::new(&self.monkeys, turn + 1, Some(throw)));
+                        }
+                    }
+                }
+            }
+            if let Some(history) = &mut self.history {
+                if !history.every_throw {
+                    history.snapshots.push(simulate::Snapshot::new(&self.monkeys, turn + 1, None));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn print_rules(&self) {
+        for monkey in &self.monkeys {
+            verbose!("Monkey {}:", monkey.id);
+            verbose!("  Items: {:?}", monkey.items);
+            verbose!("  Operation: {:?}", monkey.operation);
+            verbose!("  Test: {}", monkey.test_divisor);
+            verbose!("  Throw to: {} {}", monkey.throw_to.0, monkey.throw_to.1);
+        }
+    }
+
+    fn print_items(&self) {
+        for id in 0..self.monkeys.len() {
+            let monkey = &self.monkeys[id];
+            trace!("Monkey {}: {:?}", id, monkey.items);
+        }
+    }
+
+    // product of the two largest inspection counts after the given turns
+    fn monkey_business(&mut self, num_turns: usize, relief: bool) -> Result<usize, SolveError> {
+        self.run(num_turns, relief)?;
+        let mut inspections = self.monkeys.iter().map(|monkey| monkey.inspections).collect::<Vec<usize>>();
+        inspections.sort_unstable_by(|a, b| b.cmp(a));
+        inspections
+            .iter()
+            .take(2)
+            .try_fold(1usize, |product, &count| product.checked_mul(count))
+            .ok_or_else(|| SolveError::new("monkey business overflowed"))
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = MonkeyBusiness;
+    type Answer1 = Result<usize, SolveError>;
+    type Answer2 = Result<usize, SolveError>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut monkey_business = MonkeyBusiness::new();
+        monkey_business.load(input)?;
+        Ok(monkey_business)
+    }
+
+    // monkey business after 20 turns, with relief after each inspection
+    fn part1(monkey_business: &Self::Input) -> Result<usize, SolveError> {
+        let mut monkey_business = monkey_business.clone();
+        monkey_business.print_rules();
+        monkey_business.monkey_business(20, true)
+    }
+
+    // monkey business after 10000 turns, without relief
+    fn part2(monkey_business: &Self::Input) -> Result<usize, SolveError> {
+        let mut monkey_business = monkey_business.clone();
+        monkey_business.print_rules();
+        monkey_business.monkey_business(10000, false)
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+// None if it does not fit in a u64
+fn lcm(a: u64, b: u64) -> Option<u64> {
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+// read one of the "If" lines, which names the monkey to throw to.
+// The monkey is checked later, so a ready-made error is saved with it.
+fn read_throw(line: &mut Line, branch: &str, throws: &mut Vec<(usize, ParseError)>) -> Result<usize, ParseError> {
+    line.keyword("If")?;
+    line.keyword(branch)?;
+    line.keyword("throw")?;
+    line.keyword("to")?;
+    line.keyword("monkey")?;
+    line.skip_spaces();
+    let error = line.error("a monkey");
+    let monkey_id = line.number()?;
+    line.end()?;
+    throws.push((monkey_id, error));
+    Ok(monkey_id)
+}
@@ -0,0 +1,96 @@
+// the items held by each monkey as they are thrown around, for the visualizer
+
+use common::simulate::{Frame, Simulation};
+
+use crate::{Day11, Monkey, MonkeyBusiness};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct History {
+    // snapshot after every throw, or only after every round
+    pub(crate) every_throw: bool,
+    pub(crate) snapshots: Vec<Snapshot>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Throw {
+    pub(crate) from: usize,
+    pub(crate) to: usize,
+    // worry level of the item after inspection
+    pub(crate) item: u64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Snapshot {
+    // 1-based round, or 0 before the first one
+    round: usize,
+    throw: Option<Throw>,
+    items: Vec<Vec<u64>>,
+    inspections: Vec<usize>,
+}
+
+impl Snapshot {
+    pub(crate) fn new(monkeys: &[Monkey], round: usize, throw: Option<Throw>) -> Snapshot {
+        Snapshot {
+            round,
+            throw,
+            items: monkeys.iter().map(|monkey| monkey.items.clone()).collect(),
+            inspections: monkeys.iter().map(|monkey| monkey.inspections).collect(),
+        }
+    }
+}
+
+impl Simulation for Day11 {
+    type State = Snapshot;
+
+    // part 1 records every throw of its 20 rounds,
+    // part 2 only the end of each of its 10000 rounds
+    fn record(monkey_business: &MonkeyBusiness, part: u8) -> Vec<Snapshot> {
+        let mut monkey_business = monkey_business.clone();
+        let first = Snapshot::new(&monkey_business.monkeys, 0, None);
+        monkey_business.history = Some(History {
+            every_throw: part == 1,
+            snapshots: vec![first],
+        });
+        // an overflow ends the recording where it happened
+        let _ = if part == 1 {
+            monkey_business.run(20, true)
+        } else {
+            monkey_business.run(10000, false)
+        };
+        monkey_business.history.unwrap().snapshots
+    }
+
+    fn frame(snapshots: &[Snapshot], step: usize) -> Frame {
+        let snapshot = &snapshots[step];
+        let caption = match &snapshot.throw {
+            Some(throw) => format!(
+                "round {}: monkey {} throws {} to monkey {}",
+                snapshot.round, throw.from, throw.item, throw.to
+            ),
+            None if snapshot.round == 0 => "starting items".to_string(),
+            None => format!("after round {}", snapshot.round),
+        };
+        // one line per monkey, marking the monkeys involved in the throw
+        let mut text = String::new();
+        for (id, items) in snapshot.items.iter().enumerate() {
+            let marker = match &snapshot.throw {
+                Some(throw) if throw.from == id => '>',
+                Some(throw) if throw.to == id => '*',
+                _ => ' ',
+            };
+            let items = items.iter().map(|item| item.to_string()).collect::<Vec<String>>();
+            text += &format!(
+                "{} Monkey {} ({} inspected): {}\n",
+                marker,
+                id,
+                snapshot.inspections[id],
+                items.join(", ")
+            );
+        }
+        let frame = Frame::from_text(caption, &text);
+        match &snapshot.throw {
+            Some(throw) => frame.with_focus((0, throw.to)),
+            None => frame,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use common::Solution;
+use common::SolveError;
+use day11::Day11;
+
+fn monkey(id: usize, items: &str, operation: &str, divisor: u64, to: (usize, usize)) -> String {
+    format!(
+        "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n\n",
+        id, items, operation, divisor, to.0, to.1
+    )
+}
+
+#[test]
+fn overflowing_worry_levels_are_errors() {
+    let text = monkey(0, "5000000000", "old * old", 2, (0, 0));
+    let monkey_business = Day11::parse(&text).unwrap();
+    let overflowed = Err(SolveError::new("worry level overflowed in turn 1, inspected by monkey 0"));
+    assert_eq!(Day11::part1(&monkey_business), overflowed);
+    assert_eq!(Day11::part2(&monkey_business), overflowed);
+}
+
+#[test]
+fn divisors_too_big_to_multiply_are_left_alone() {
+    // the least common multiple of the divisors does not fit in a u64
+    let big = 4294967291;
+    let text = monkey(0, "7, 10", "old + 1", big, (1, 1))
+        + &monkey(1, "3", "old + 2", big - 2, (2, 2))
+        + &monkey(2, "1", "old * 2", 4294967279, (0, 0));
+    let monkey_business = Day11::parse(&text).unwrap();
+    assert_eq!(Day11::part1(&monkey_business), Ok(6320));
+    assert_eq!(Day11::part2(&monkey_business), Err(SolveError::new("worry level overflowed in turn 61, inspected by monkey 2")));
+}
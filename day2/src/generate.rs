@@ -0,0 +1,29 @@
+// random strategy guides, one round per line
+
+use common::generate::{Generator, Rng, StdRng};
+
+use crate::Day2;
+
+const MOVES: [char; 3] = ['R', 'P', 'S'];
+
+impl Generator for Day2 {
+    // number of rounds
+    const DEFAULT_SIZE: usize = 2500;
+
+    fn generate(rng: &mut StdRng, size: usize) -> Vec<(char, char)> {
+        (0..size)
+            .map(|_| (MOVES[rng.gen_range(0..3)], MOVES[rng.gen_range(0..3)]))
+            .collect()
+    }
+
+    // rock, paper and scissors are A, B, C for them and X, Y, Z for me
+    fn to_text(rounds: &Vec<(char, char)>) -> String {
+        rounds
+            .iter()
+            .map(|(their_move, my_move)| {
+                let index = |c: &char| MOVES.iter().position(|m| m == c).unwrap() as u8;
+                format!("{} {}\n", (b'A' + index(their_move)) as char, (b'X' + index(my_move)) as char)
+            })
+            .collect()
+    }
+}
@@ -1,18 +1,22 @@
+use common::parse::lines;
+use common::{ParseError, Solution};
 
-// read an input file containing lines of the form:
+mod generate;
+
+
+// read input text containing lines of the form:
 //  <their-move> <my-move>
 // where <their-move> and <my-move> are capital letters.
 // returns a vector of tuples of the form:
 //  (their-move, my-move)
-fn read_input() -> Vec<(char, char)> {
-    std::fs::read_to_string("input")
-        .unwrap()
-        .lines()
-        .map(|line| {
-            let mut chars = line.chars();
-            let their_move = chars.next().unwrap();
-            let my_move = chars.skip(1).next().unwrap();
-            (their_move, my_move)
+fn read_input(text: &str) -> Result<Vec<(char, char)>, ParseError> {
+    lines(text)
+        .map(|mut line| {
+            let their_move = line.char_matching("one of A, B, C", |c| "ABC".contains(c))?;
+            line.literal(" ")?;
+            let my_move = line.char_matching("one of X, Y, Z", |c| "XYZ".contains(c))?;
+            line.end()?;
+            Ok((their_move, my_move))
         })
         .collect()
 }
@@ -150,20 +154,31 @@ fn required_move_pair((their_move, outcome): (char, char)) -> (char, char) {
     (their_move, my_required_move((their_move, outcome)))
 }
 
-fn main() {
-    // read the input file
-    let moves = read_input();
-    let moves = convert_moves_part1(&moves);
-    println!("Part 1:");
-    let (my_score, their_score) = total_score(&moves);
-    println!("{} {}", my_score, their_score);
-
-    println!("Part 2:");
-    let their_moves_and_outcomes = convert_moves_part2(&moves);
-    let moves = their_moves_and_outcomes
-        .iter()
-        .map(|(their_move, outcome)| required_move_pair((*their_move, *outcome)))
-        .collect::<Vec<(char, char)>>();
-    let (my_score, their_score) = total_score(&moves);
-    println!("{} {}", my_score, their_score);
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<(char, char)>;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(convert_moves_part1(&read_input(input)?))
+    }
+
+    // my total score, reading the second column as my move
+    fn part1(moves: &Self::Input) -> isize {
+        let (my_score, _their_score) = total_score(moves);
+        my_score
+    }
+
+    // my total score, reading the second column as the desired outcome
+    fn part2(moves: &Self::Input) -> isize {
+        let their_moves_and_outcomes = convert_moves_part2(moves);
+        let moves = their_moves_and_outcomes
+            .iter()
+            .map(|(their_move, outcome)| required_move_pair((*their_move, *outcome)))
+            .collect::<Vec<(char, char)>>();
+        let (my_score, _their_score) = total_score(&moves);
+        my_score
+    }
 }
@@ -0,0 +1,100 @@
+// random rucksacks in groups of three.
+// Each rucksack has exactly one item type in both compartments,
+// and each group has exactly one item type, the badge, in all three.
+
+use common::generate::{Generator, Rng, SliceRandom, StdRng};
+
+use crate::{Day3, Rucksack};
+
+impl Generator for Day3 {
+    // number of groups of three rucksacks
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(rng: &mut StdRng, size: usize) -> Vec<Rucksack> {
+        let letters = ('a'..='z').chain('A'..='Z').collect::<Vec<char>>();
+        let mut rucksacks = Vec::new();
+        for group in 0..size {
+            let badge = *letters.choose(rng).unwrap();
+            // keep every other item out of at least one rucksack in the group,
+            // so that the badge is the only item they all share
+            let left_out = letters
+                .iter()
+                .map(|_| rng.gen_range(0..3))
+                .collect::<Vec<usize>>();
+            for elf in 0..3 {
+                let allowed = letters
+                    .iter()
+                    .zip(&left_out)
+                    .filter(|&(&c, &out)| c != badge && out != elf)
+                    .map(|(&c, _)| c)
+                    .collect::<Vec<char>>();
+                let mut line = generate_rucksack(rng, &allowed, badge);
+                // the items' order within a compartment doesn't matter
+                let half = line.len() / 2;
+                line[..half].shuffle(rng);
+                line[half..].shuffle(rng);
+                let mut rucksack = Rucksack::from_string(&line.iter().collect::<String>());
+                rucksack.group = group as u32;
+                rucksacks.push(rucksack);
+            }
+        }
+        rucksacks
+    }
+
+    fn to_text(rucksacks: &Vec<Rucksack>) -> String {
+        rucksacks
+            .iter()
+            .map(|rucksack| {
+                let items = rucksack.left.iter().chain(&rucksack.right).collect::<String>();
+                items + "\n"
+            })
+            .collect()
+    }
+}
+
+// items of one rucksack, left compartment first, holding the badge
+// and otherwise only allowed items
+fn generate_rucksack(rng: &mut StdRng, allowed: &[char], badge: char) -> Vec<char> {
+    // the item in both compartments, which may be the badge itself
+    let common = if rng.gen_bool(0.1) { badge } else { *allowed.choose(rng).unwrap() };
+    // split the remaining items between the compartments
+    let mut left_only = Vec::new();
+    let mut right_only = Vec::new();
+    for &c in allowed {
+        if c != common {
+            if rng.gen() {
+                left_only.push(c);
+            } else {
+                right_only.push(c);
+            }
+        }
+    }
+    let badge_left = rng.gen();
+    if common != badge {
+        if badge_left {
+            left_only.push(badge);
+        } else {
+            right_only.push(badge);
+        }
+    }
+
+    let len = rng.gen_range(2..=16);
+    let mut left = vec![common];
+    let mut right = vec![common];
+    if common != badge {
+        if badge_left {
+            left.push(badge);
+        } else {
+            right.push(badge);
+        }
+    }
+    for (items, only) in [(&mut left, &left_only), (&mut right, &right_only)] {
+        while items.len() < len {
+            // an empty pool can only repeat the items already there
+            let item = *only.choose(rng).unwrap_or(&items[0]);
+            items.push(item);
+        }
+    }
+    left.extend(right);
+    left
+}
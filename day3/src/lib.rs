@@ -0,0 +1,259 @@
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use common::log::{self, Level};
+use common::parse::lines;
+use common::{trace, ParseError, Solution};
+
+mod generate;
+
+// struct rucksack contains two compartments
+// each compartment can hold any number of items
+// each item is represented by a char
+// rucksacks also have a unique identifier
+pub struct Rucksack {
+    left: Vec<char>,
+    right: Vec<char>,
+    uid: u64,
+    group: u32,
+}
+
+// uid of the next rucksack made, counting up so that uids never repeat
+// (random ones would need a source of entropy, which WebAssembly lacks)
+static NEXT_UID: AtomicU64 = AtomicU64::new(1);
+
+// implement the Rucksack struct
+impl Rucksack {
+    // create a new rucksack with no items
+    // and a fresh uid
+    fn new() -> Rucksack {
+        Rucksack {
+            left: Vec::new(),
+            right: Vec::new(),
+            uid: NEXT_UID.fetch_add(1, Ordering::Relaxed),
+            group: 0,
+        }
+    }
+
+    // version of constructor that takes a string
+    // and adds each char to the rucksack
+    // and then balances the rucksack
+    fn from_string(s: &str) -> Rucksack {
+        let mut rucksack = Rucksack::new();
+        for c in s.chars() {
+            rucksack.add(c);
+        }
+        rucksack.balance();
+        rucksack
+    }
+
+    // add an item to the rucksack
+    // this always goes into the left compartment
+    fn add(&mut self, item: char) {
+        self.left.push(item);
+    }
+
+    // balance the rucksack
+    // this moves the latter half of the items from
+    // the left to the right compartment
+    fn balance(&mut self) {
+        assert!(self.right.is_empty());
+        assert!(self.left.len().is_multiple_of(2));
+        let midpoint = self.left.len() / 2;
+        self.right = self.left.split_off(midpoint);
+    }
+
+    // find common items
+    // returns a set of items that are in both compartments
+    // (No duplicates in returned set.)
+    // (case-sensitive)
+    fn find_common(&self) -> HashSet<char> {
+        let mut common = HashSet::new();
+        for item in &self.left {
+            if self.right.contains(item) {
+                common.insert(*item);
+            }
+        }
+        common
+    }
+
+    // print the rucksack
+    fn print(&self) {
+        trace!("Rucksack #{}", self.uid);
+        trace!("  Left:  {}", self.left.iter().collect::<String>());
+        trace!("  Right: {}", self.right.iter().collect::<String>());
+    }
+
+    // convert a rucksack to a set of items
+    // without duplicates
+    // combining items from both compartments
+    fn to_set(&self) -> HashSet<char> {
+        let mut set = HashSet::new();
+        for item in &self.left {
+            set.insert(*item);
+        }
+        for item in &self.right {
+            set.insert(*item);
+        }
+        set
+    }
+}
+
+// equality operator for rucksacks
+// rucksacks are equal if they have the same uid
+impl PartialEq for Rucksack {
+    fn eq(&self, other: &Self) -> bool {
+        self.uid == other.uid
+    }
+}
+
+impl Eq for Rucksack {}
+
+// organize a list of rucksacks into groups
+// return a dictionary mapping group id to list of rucksacks in that group
+// do not clone the rucksacks
+fn organize(rucksacks: &Vec<Rucksack>) -> std::collections::HashMap<u32, Vec<&Rucksack>> {
+    let mut groups = std::collections::HashMap::<u32, Vec<&Rucksack>>::new();
+    for rucksack in rucksacks {
+        groups.entry(rucksack.group).or_default().push(rucksack);
+    }
+    groups
+}
+
+// find intersection of all rucksacks in a group
+fn intersect_group(rucksacks: &Vec<&Rucksack>) -> HashSet<char> {
+    // apply bitwise and operator to all rucksacks in group
+    let mut intersection = rucksacks[0].to_set();
+    for rucksack in rucksacks {
+        intersection = intersection
+            .intersection(&rucksack.to_set())
+            .copied()
+            .collect();
+    }
+    intersection
+}
+
+// get priority of item
+// a--z: 1--26
+// A--Z: 27--52
+fn get_priority(item: char) -> u32 {
+    let mut priority = 0;
+    if item.is_ascii_lowercase() {
+        priority = item as u32 - 96;
+    } else if item.is_ascii_uppercase() {
+        priority = item as u32 - 64 + 26;
+    }
+    assert!(priority > 0);
+    priority
+}
+
+// load rucksacks from the input text
+// one line per rucksack
+// each line contains a string of items
+// Each rucksack must have an item common to both compartments,
+// and each group of three rucksacks must share an item.
+fn load_rucksacks(contents: &str) -> Result<Vec<Rucksack>, ParseError> {
+    let mut rucksacks = Vec::new();
+    let mut group = 0;
+    let mut elf_in_group = 0;
+    let mut num_lines = 0;
+    for mut line in lines(contents) {
+        num_lines = line.number;
+        while !line.at_end() {
+            line.char_matching("an item from a-z or A-Z", |c| c.is_ascii_alphabetic())?;
+        }
+        if !line.text.len().is_multiple_of(2) {
+            return Err(line.error_at(0, "a rucksack with an even number of items"));
+        }
+        let mut rucksack = Rucksack::from_string(line.text);
+        if rucksack.find_common().is_empty() {
+            return Err(line.error_at(0, "a rucksack with an item in both compartments"));
+        }
+        rucksack.group = group;
+        rucksacks.push(rucksack);
+        elf_in_group += 1;
+        if elf_in_group == 3 {
+            let members = rucksacks[rucksacks.len() - 3..].iter().collect();
+            if intersect_group(&members).is_empty() {
+                return Err(line.error_at(0, "a rucksack sharing an item with the two before it"));
+            }
+            elf_in_group = 0;
+            group += 1;
+        }
+    }
+    if elf_in_group != 0 {
+        return Err(ParseError::end_of_input(num_lines, "a complete group of three rucksacks"));
+    }
+    Ok(rucksacks)
+}
+
+// get an arbitrary item from a set
+fn get_item(set: &HashSet<char>) -> char {
+    set.iter().next().copied().unwrap_or('?')
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Rucksack>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_rucksacks(input)
+    }
+
+    // sum of the priorities of the items common to both compartments
+    fn part1(rucksacks: &Self::Input) -> u32 {
+        if log::enabled(Level::Trace) {
+            // print the rucksacks
+            for rucksack in rucksacks {
+                rucksack.print();
+            }
+
+            // find common items in each rucksack
+            // asserting that there be exactly one common item in each rucksack
+            for (i, rucksack) in rucksacks.iter().enumerate() {
+                let common = get_item(&rucksack.find_common());
+                trace!("Rucksack #{} common item = {}", i + 1, common);
+            }
+        }
+
+        // compute the sum of the priorities of the common items in each rucksack
+        let mut sum = 0;
+        for rucksack in rucksacks {
+            let common = get_item(&rucksack.find_common());
+            sum += get_priority(common);
+        }
+        sum
+    }
+
+    // sum of the priorities of the badge item common to each group of three
+    fn part2(rucksacks: &Self::Input) -> u32 {
+        // organize rucksacks into groups
+        let groups = organize(rucksacks);
+
+        // find intersection of each group
+        let mut sum = 0;
+        for group in groups.values() {
+            let intersection = intersect_group(group);
+            let item = get_item(&intersection);
+            trace!("Group {} intersection = {}", group[0].group, item);
+            sum += get_priority(item);
+        }
+        sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn priorities() {
+        assert_eq!(get_priority('a'), 1);
+        assert_eq!(get_priority('z'), 26);
+        assert_eq!(get_priority('A'), 27);
+        assert_eq!(get_priority('Z'), 52);
+    }
+}
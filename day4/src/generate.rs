@@ -0,0 +1,34 @@
+// random pairs of section assignments
+
+use common::generate::{Generator, Rng, StdRng};
+
+use crate::Day4;
+
+// sections are numbered from 1 to this
+const SECTIONS: u32 = 99;
+
+impl Generator for Day4 {
+    // number of pairs
+    const DEFAULT_SIZE: usize = 1000;
+
+    fn generate(rng: &mut StdRng, size: usize) -> Vec<(u32, u32, u32, u32)> {
+        let range = |rng: &mut StdRng| {
+            let start = rng.gen_range(1..=SECTIONS);
+            (start, rng.gen_range(start..=SECTIONS))
+        };
+        (0..size)
+            .map(|_| {
+                let (a1, a2) = range(rng);
+                let (b1, b2) = range(rng);
+                (a1, a2, b1, b2)
+            })
+            .collect()
+    }
+
+    fn to_text(pairs: &Vec<(u32, u32, u32, u32)>) -> String {
+        pairs
+            .iter()
+            .map(|(a1, a2, b1, b2)| format!("{}-{},{}-{}\n", a1, a2, b1, b2))
+            .collect()
+    }
+}
@@ -0,0 +1,68 @@
+/*
+ * Input file format is many lines containing a pair of assignments of the form:
+ *
+ *  [\d]+-[\d]+,[\d]+-[\d]+
+ *
+ * The first range is the section assignment for the first elf.
+ * The second range is the section assignment for the second elf in a pair.
+ *
+ * Our goal is to find the number of assignment pairs in which one assignment is entirely contained
+ * within the other.
+ */
+
+use common::parse::lines;
+use common::{ParseError, Solution};
+
+mod generate;
+
+fn load_input(text: &str) -> Result<Vec<(u32, u32, u32, u32)>, ParseError> {
+    let mut input = Vec::new();
+    // read input text line by line
+    for mut line in lines(text) {
+        // the line consists of two ranges separated by a comma
+        let a1 = line.number()?;
+        line.literal("-")?;
+        let a2 = line.number()?;
+        line.literal(",")?;
+        let b1 = line.number()?;
+        line.literal("-")?;
+        let b2 = line.number()?;
+        line.end()?;
+        input.push((a1, a2, b1, b2));
+    }
+    Ok(input)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<(u32, u32, u32, u32)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_input(input)
+    }
+
+    // number of pairs in which one assignment contains the other
+    fn part1(input: &Self::Input) -> usize {
+        let mut count = 0;
+        for (a1, a2, b1, b2) in input {
+            if (a1 >= b1 && a2 <= b2) || (b1 >= a1 && b2 <= a2) {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    // now, find the number of pairs that overlap at all
+    fn part2(input: &Self::Input) -> usize {
+        let mut count = 0;
+        for (a1, a2, b1, b2) in input {
+            if (a1 <= b1 && a2 >= b1) || (b1 <= a1 && b2 >= a1) {
+                count += 1;
+            }
+        }
+        count
+    }
+}
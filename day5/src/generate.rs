@@ -0,0 +1,56 @@
+// random stacks of crates, and moves which never take more crates
+// than a stack holds
+
+use common::generate::{Generator, Rng, StdRng};
+
+use crate::{draw_stacks, Day5, Move, Stacks};
+
+const NUM_STACKS: usize = 9;
+const MAX_HEIGHT: usize = 8;
+
+impl Generator for Day5 {
+    // number of moves
+    const DEFAULT_SIZE: usize = 500;
+
+    fn generate(rng: &mut StdRng, size: usize) -> (Stacks, Vec<Move>) {
+        let mut stacks = vec![Vec::new(); NUM_STACKS];
+        // at least one crate, so that there is always something to move
+        stacks[rng.gen_range(0..NUM_STACKS)].push(random_crate(rng));
+        for stack in stacks.iter_mut() {
+            let height = rng.gen_range(stack.len()..=MAX_HEIGHT);
+            while stack.len() < height {
+                stack.push(random_crate(rng));
+            }
+        }
+
+        let mut heights = stacks.iter().map(|stack| stack.len()).collect::<Vec<usize>>();
+        let mut moves = Vec::new();
+        for _ in 0..size {
+            let from = loop {
+                let from = rng.gen_range(0..NUM_STACKS);
+                if heights[from] > 0 {
+                    break from;
+                }
+            };
+            let to = (from + rng.gen_range(1..NUM_STACKS)) % NUM_STACKS;
+            let quantity = rng.gen_range(1..=heights[from]);
+            heights[from] -= quantity;
+            heights[to] += quantity;
+            moves.push((from + 1, to + 1, quantity));
+        }
+        (stacks, moves)
+    }
+
+    fn to_text((stacks, moves): &(Stacks, Vec<Move>)) -> String {
+        let mut text = draw_stacks(stacks);
+        text += "\n";
+        for (from_stack, to_stack, quantity) in moves {
+            text += &format!("move {} from {} to {}\n", quantity, from_stack, to_stack);
+        }
+        text
+    }
+}
+
+fn random_crate(rng: &mut StdRng) -> char {
+    rng.gen_range('A'..='Z')
+}
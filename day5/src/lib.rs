@@ -0,0 +1,250 @@
+
+/*
+
+Input example:
+
+    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+
+*/
+
+use common::parse::{lines, Line};
+use common::{trace, verbose, ParseError, Solution};
+
+mod generate;
+mod simulate;
+
+// stacks of crates, bottom crate first
+type Stacks = Vec<Vec<char>>;
+
+// (from_stack, to_stack, quantity)
+type Move = (usize, usize, usize);
+
+// read from input text,
+// outpuiting a vector of vectors of chars representing each stack of crates,
+// with the top crate at the end of the vector,
+// and a vector of moves to be made, each move being a tuple of the form 
+// (from_stack, to_stack, quantity)
+fn read_input(text: &str) -> Result<(Stacks, Vec<Move>), ParseError> {
+    let mut lines = lines(text);
+    let stacks = read_input_stacks(&mut lines)?;
+    let moves = read_input_moves(&mut lines, &stacks)?;
+    Ok((stacks, moves))
+}
+
+// read the stacks of crates from the input text
+fn read_input_stacks<'a>(lines: &mut impl Iterator<Item = Line<'a>>) -> Result<Stacks, ParseError> {
+    let mut stacks = Vec::new();
+    let mut num_lines = 0;
+    // read line by line
+    for mut line in lines.by_ref() {
+        num_lines = line.number;
+        // if the line is empty, skip it
+        if line.is_empty() {
+           continue;
+        }
+        // if the line starts with a number, we have reached the footer
+        // which numbers the stacks from 1
+        if line.rest().trim_start().starts_with(|c: char| c.is_ascii_digit()) {
+            let mut count = 0;
+            line.skip_spaces();
+            while !line.at_end() {
+                let start = line.pos();
+                if line.number::<usize>()? != count + 1 {
+                    return Err(line.error_at(start, &format!("stack number {}", count + 1)));
+                }
+                count += 1;
+                line.skip_spaces();
+            }
+            if count < stacks.len() {
+                return Err(line.error(&format!("stack number {}", count + 1)));
+            }
+            stacks.resize(count, Vec::new());
+            return Ok(stacks);
+        }
+        // the line contains a slice of stacks in the form:
+        // r"((   |\[\w\]) )+"
+        // where each column is either empty or contains a crate
+        // and each crate contains a capital letter.
+        let mut i = 0;
+        loop {
+            // expand stacks if necessary
+            if stacks.len() <= i {
+                stacks.push(Vec::new());
+            }
+            if line.rest().starts_with("   ") {
+                line.literal("   ")?;
+            } else {
+                line.literal("[")?;
+                let crate_char = line.char_matching("a crate letter", |c| c.is_ascii_uppercase())?;
+                line.literal("]")?;
+                stacks[i].insert(0, crate_char);
+            }
+            i += 1;
+            if line.at_end() {
+                break;
+            }
+            line.literal(" ")?;
+            if line.at_end() {
+                break;
+            }
+        }
+    }
+    Err(ParseError::end_of_input(num_lines, "a line numbering the stacks"))
+}
+
+// read the moves from the input text,
+// checking that each move refers to existing stacks
+// and that there are enough crates on the stack to move
+fn read_input_moves<'a>(lines: &mut impl Iterator<Item = Line<'a>>, stacks: &Stacks) -> Result<Vec<Move>, ParseError> {
+    let mut moves = Vec::new();
+    let mut heights = stacks.iter().map(|stack| stack.len()).collect::<Vec<usize>>();
+    // read line by line
+    for mut line in lines {
+        // if the line is empty, skip it
+        if line.is_empty() {
+           continue;
+        }
+        // the line contains a move in the form:
+        // r"move \d+ from \d+ to \d+"
+        // where the first number is the quantity of crates to move,
+        // the second is the stack to move from,
+        // and the third is the stack to move to.
+        line.keyword("move")?;
+        line.skip_spaces();
+        let quantity_pos = line.pos();
+        let quantity = line.number::<usize>()?;
+        line.keyword("from")?;
+        let from_stack = read_stack_number(&mut line, stacks.len())?;
+        line.keyword("to")?;
+        let to_stack = read_stack_number(&mut line, stacks.len())?;
+        line.end()?;
+        if quantity > heights[from_stack - 1] {
+            let expected = format!("a quantity of at most {}", heights[from_stack - 1]);
+            return Err(line.error_at(quantity_pos, &expected));
+        }
+        heights[from_stack - 1] -= quantity;
+        heights[to_stack - 1] += quantity;
+        moves.push((from_stack, to_stack, quantity));
+    }
+    Ok(moves)
+}
+
+// read a 1-based stack number, which must refer to one of the stacks
+fn read_stack_number(line: &mut Line, num_stacks: usize) -> Result<usize, ParseError> {
+    line.skip_spaces();
+    let start = line.pos();
+    let stack = line.number::<usize>()?;
+    if stack < 1 || stack > num_stacks {
+        return Err(line.error_at(start, &format!("a stack number from 1 to {}", num_stacks)));
+    }
+    Ok(stack)
+}
+
+// move crates from one stack to another
+fn move_part1(stacks: &mut [Vec<char>], moves: &[Move]) {
+    for (from_stack, to_stack, quantity) in moves {
+        // pop one crate at a time from the from_stack,
+        // and push it onto the to_stack
+        for _ in 0..*quantity {
+            let crate_char = stacks[*from_stack - 1].pop().unwrap();
+            stacks[*to_stack - 1].push(crate_char);
+        }
+    }
+}
+
+// move crates, this time maintaining the order of the crates
+// in each stack as they are moved
+fn move_part2(stacks: &mut [Vec<char>], moves: &[Move]) {
+    for (from_stack, to_stack, quantity) in moves {
+        // pop the crates from the from_stack,
+        // and push them onto a temporary stack
+        let mut temp_stack = Vec::new();
+        for _ in 0..*quantity {
+            let crate_char = stacks[*from_stack - 1].pop().unwrap();
+            temp_stack.push(crate_char);
+        }
+        // pop the crates from the temporary stack,
+        // and push them onto the to_stack
+        for _ in 0..*quantity {
+            let crate_char = temp_stack.pop().unwrap();
+            stacks[*to_stack - 1].push(crate_char);
+        }
+    }
+}
+
+// draw the stacks the way the puzzle input does,
+// top row first, with a footer numbering the stacks
+fn draw_stacks(stacks: &Stacks) -> String {
+    let mut text = String::new();
+    let height = stacks.iter().map(|stack| stack.len()).max().unwrap_or(0);
+    for row in (0..height).rev() {
+        let cells = stacks
+            .iter()
+            .map(|stack| match stack.get(row) {
+                Some(c) => format!("[{}]", c),
+                None => "   ".to_string(),
+            })
+            .collect::<Vec<String>>();
+        text += &cells.join(" ");
+        text += "\n";
+    }
+    let footer = (1..=stacks.len()).map(|i| format!(" {} ", i)).collect::<Vec<String>>();
+    text += &footer.join(" ");
+    text += "\n";
+    text
+}
+
+// print the final stacks and the moves that led there,
+// returning a string with the top crate of each stack
+fn top_crates(stacks: &Stacks, moves: &[Move]) -> String {
+    // print final stacks
+    for (i, stack) in stacks.iter().enumerate() {
+        verbose!("Stack {}: {:?}", i + 1, stack);
+    }
+    // print moves
+    for (from_stack, to_stack, quantity) in moves {
+        trace!("Move {} from {} to {}", quantity, from_stack, to_stack);
+    }
+    // construct a string with the top crate of each stack
+    let mut top_crates = String::new();
+    for stack in stacks {
+        if let Some(top) = stack.last() {
+            top_crates.push(*top);
+        }
+    }
+    top_crates
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (Stacks, Vec<Move>);
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
+
+    // top crates after moving crates one at a time
+    fn part1((stacks, moves): &Self::Input) -> String {
+        let mut stacks = stacks.clone();
+        move_part1(&mut stacks, moves);
+        top_crates(&stacks, moves)
+    }
+
+    // top crates after moving crates several at a time
+    fn part2((stacks, moves): &Self::Input) -> String {
+        let mut stacks = stacks.clone();
+        move_part2(&mut stacks, moves);
+        top_crates(&stacks, moves)
+    }
+}
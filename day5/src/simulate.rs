@@ -0,0 +1,47 @@
+// the stacks after each move, for the visualizer
+
+use common::simulate::{Frame, Simulation};
+
+use crate::{draw_stacks, move_part1, move_part2, Day5, Move, Stacks};
+
+impl Simulation for Day5 {
+    // stacks after the move, if any
+    type State = (Stacks, Option<Move>);
+
+    fn record((stacks, moves): &(Stacks, Vec<Move>), part: u8) -> Vec<(Stacks, Option<Move>)> {
+        let mut stacks = stacks.clone();
+        let mut states = vec![(stacks.clone(), None)];
+        for m in moves {
+            if part == 1 {
+                move_part1(&mut stacks, &[*m]);
+            } else {
+                move_part2(&mut stacks, &[*m]);
+            }
+            states.push((stacks.clone(), Some(*m)));
+        }
+        states
+    }
+
+    fn frame(states: &[(Stacks, Option<Move>)], step: usize) -> Frame {
+        let (stacks, last_move) = &states[step];
+        let caption = match last_move {
+            Some((from_stack, to_stack, quantity)) => format!("move {} from {} to {}", quantity, from_stack, to_stack),
+            None => "starting stacks".to_string(),
+        };
+        // pad the drawing to the tallest the stacks get, so that it stays put
+        let tallest = states.iter().flat_map(|(stacks, _)| stacks.iter().map(|stack| stack.len())).max();
+        let height = stacks.iter().map(|stack| stack.len()).max();
+        let padding = "\n".repeat(tallest.unwrap_or(0) - height.unwrap_or(0));
+        let frame = Frame::from_text(caption, &(padding + &draw_stacks(stacks)));
+        // keep the top of the stack the crates went to in view,
+        // counting rows down from the footer on the last row
+        match last_move {
+            Some((_, to_stack, _)) => {
+                let footer = frame.picture.height() - 1;
+                let top = footer - stacks[to_stack - 1].len();
+                frame.with_focus(((to_stack - 1) * 4 + 1, top))
+            }
+            None => frame,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+// random datastreams, with a start-of-message marker planted in them
+// so that both markers are always found
+
+use common::generate::{Generator, Rng, SliceRandom, StdRng};
+
+use crate::Day6;
+
+// length of the start-of-message marker
+const MARKER: usize = 14;
+
+impl Generator for Day6 {
+    // length of the datastream
+    const DEFAULT_SIZE: usize = 4096;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        // room for the marker, which may end the datastream
+        let size = size.max(MARKER);
+        let mut datastream = (0..size).map(|_| rng.gen_range('a'..='z')).collect::<Vec<char>>();
+        let mut marker = ('a'..='z').collect::<Vec<char>>();
+        marker.shuffle(rng);
+        let start = rng.gen_range(0..=size - MARKER);
+        datastream[start..start + MARKER].copy_from_slice(&marker[..MARKER]);
+        datastream.into_iter().collect()
+    }
+
+    fn to_text(datastream: &String) -> String {
+        format!("{}\n", datastream)
+    }
+}
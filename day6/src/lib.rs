@@ -0,0 +1,67 @@
+use common::parse::lines;
+use common::{ParseError, Solution};
+
+mod generate;
+
+
+// find start-of-packet marker
+// which is the 1-based index of the first character in the string
+// for which the past n characters (inclusive) are all different
+// add a parameter for the number of characters to check
+fn find_marker(s: &str, n: usize) -> usize {
+    let mut marker = 0;
+    // the window may end with the last character
+    for i in n..=s.len() {
+        // get the n characters before the current character
+        let chars = &s[i - n..i];
+        //println!("   {} -> {}", i, chars);
+        // check if all characters are different
+        if chars.chars().all(|c| chars.chars().filter(|&x| x == c).count() == 1) {
+            marker = i;
+            break;
+        }
+    }
+    marker
+}
+
+// list of example strings and their expected results
+pub const EXAMPLES: [(&str, usize, usize); 5] = [
+    ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+    ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+    ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+    ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+    ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+];
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    // the input is a single line of lowercase letters
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut datastream = None;
+        for mut line in lines(input.trim_end()) {
+            if datastream.is_some() {
+                return Err(line.error("end of input"));
+            }
+            while !line.at_end() {
+                line.char_matching("a letter from a-z", |c| c.is_ascii_lowercase())?;
+            }
+            datastream = Some(line.text.to_string());
+        }
+        Ok(datastream.unwrap_or_default())
+    }
+
+    // start-of-packet marker
+    fn part1(input: &Self::Input) -> usize {
+        find_marker(input, 4)
+    }
+
+    // start-of-message marker
+    fn part2(input: &Self::Input) -> usize {
+        find_marker(input, 14)
+    }
+}
@@ -0,0 +1,14 @@
+use common::Solution;
+use day6::Day6;
+
+#[test]
+fn markers_can_end_the_datastream() {
+    let answers = |text: &str| {
+        let datastream = Day6::parse(text).unwrap();
+        (Day6::part1(&datastream), Day6::part2(&datastream))
+    };
+    assert_eq!(answers("abcd\n"), (4, 0));
+    assert_eq!(answers("aabcd"), (5, 0));
+    assert_eq!(answers("abcdefghijklmn"), (4, 14));
+    assert_eq!(answers("aaa"), (0, 0));
+}
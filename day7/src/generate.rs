@@ -0,0 +1,63 @@
+// random directory trees, written out as the log of a terminal session
+// which lists every directory once, depth first
+
+use common::generate::{Generator, Rng, StdRng};
+
+use crate::{Day7, FileSystem, NodeId};
+
+const EXTENSIONS: [&str; 5] = ["", ".txt", ".dat", ".log", ".lst"];
+
+impl Generator for Day7 {
+    // number of files and directories
+    const DEFAULT_SIZE: usize = 450;
+
+    fn generate(rng: &mut StdRng, size: usize) -> FileSystem {
+        let mut fs = FileSystem::new();
+        let mut dirs = vec![fs.root()];
+        for _ in 0..size {
+            let dir = dirs[rng.gen_range(0..dirs.len())];
+            // a name not yet used in the directory
+            let name = loop {
+                let name = random_name(rng);
+                if fs.child(dir, &name).is_none() {
+                    break name;
+                }
+            };
+            if rng.gen_bool(0.3) {
+                dirs.push(fs.add_dir(dir, &name));
+            } else {
+                let name = name + EXTENSIONS[rng.gen_range(0..EXTENSIONS.len())];
+                fs.add_file(dir, &name, rng.gen_range(1..=300000));
+            }
+        }
+        fs
+    }
+
+    fn to_text(fs: &FileSystem) -> String {
+        let mut text = "$ cd /\n".to_string();
+        write_dir(fs, fs.root(), &mut text);
+        text
+    }
+}
+
+// ls the directory, then cd into each directory in it and back out
+fn write_dir(fs: &FileSystem, dir: NodeId, text: &mut String) {
+    *text += "$ ls\n";
+    for child in fs.children(dir) {
+        if fs.is_dir(child) {
+            *text += &format!("dir {}\n", fs.name(child));
+        } else {
+            *text += &format!("{} {}\n", fs.size(child), fs.name(child));
+        }
+    }
+    for child in fs.children(dir).filter(|&child| fs.is_dir(child)) {
+        *text += &format!("$ cd {}\n", fs.name(child));
+        write_dir(fs, child, text);
+        *text += "$ cd ..\n";
+    }
+}
+
+fn random_name(rng: &mut StdRng) -> String {
+    let len = rng.gen_range(1..=8);
+    (0..len).map(|_| rng.gen_range('a'..='z')).collect()
+}
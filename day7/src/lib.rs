@@ -0,0 +1,149 @@
+/*
+The task here is to build a tree representing a directory
+structure which we learn from a terminal log (input).
+We will then be able to query the tree for information
+about the size of a directory (which recursively sums the
+sizes of all files and subdirectories).
+
+Input example:
+
+"$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k"
+*/
+
+use common::log::{self, Level};
+use std::io::BufRead;
+
+use common::parse::{lines, Line};
+use common::{trace, verbose, LoadError, ParseError, Solution, Source};
+
+mod generate;
+pub mod materialize;
+pub mod query;
+mod shell;
+mod tree;
+
+pub use shell::{Change, Diagnostic, Shell};
+pub use tree::{FileSystem, NodeId};
+
+// the disk, and the space the update needs free on it
+const DISK_SIZE: u64 = 70000000;
+const UPDATE_SIZE: u64 = 30000000;
+
+// directories of at most this size count towards part 1
+const SMALL_DIR: u64 = 100000;
+
+/* print the tree, indenting each level
+
+Example:
+    - / (dir, size=48381165)
+      - a (dir, size=94853)
+        - e (dir, size=584)
+          - i (file, size=584)
+        ...
+*/
+fn print(fs: &FileSystem) {
+    if !log::enabled(Level::Trace) {
+        return;
+    }
+    let mut stack = vec![(fs.root(), 0)];
+    while let Some((node, depth)) = stack.pop() {
+        let kind = if fs.is_dir(node) { "dir" } else { "file" };
+        trace!("{}- {} ({}, size={})", "  ".repeat(depth), fs.name(node), kind, fs.size(node));
+        let children = fs.children(node).collect::<Vec<NodeId>>();
+        stack.extend(children.into_iter().rev().map(|child| (child, depth + 1)));
+    }
+}
+
+// read the terminal log, building the tree as we go,
+// along with the changes found when directories are listed again
+pub fn interpret(text: &str) -> Result<(FileSystem, Vec<Diagnostic>), ParseError> {
+    let mut shell = Shell::new();
+    for mut line in lines(text) {
+        shell.run(&mut line)?;
+    }
+    Ok(shell.finish())
+}
+
+// read a terminal log a line at a time, so that only the tree is kept
+// in memory however long the log, handing each change to report as soon
+// as it is found
+pub fn read_log(name: &str, mut reader: impl BufRead, mut report: impl FnMut(Diagnostic)) -> Result<FileSystem, LoadError> {
+    let mut shell = Shell::new();
+    let mut text = String::new();
+    let mut number = 0;
+    loop {
+        text.clear();
+        if reader.read_line(&mut text).map_err(|err| LoadError::Io(name.to_string(), err))? == 0 {
+            break;
+        }
+        number += 1;
+        let end = text.trim_end_matches('\n');
+        let mut line = Line::new(number, end.strip_suffix('\r').unwrap_or(end));
+        shell.run(&mut line).map_err(|err| LoadError::Parse(err.in_file(name)))?;
+        shell.drain().for_each(&mut report);
+    }
+    let (fs, diagnostics) = shell.finish();
+    diagnostics.into_iter().for_each(report);
+    Ok(fs)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = FileSystem;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (fs, diagnostics) = interpret(input)?;
+        for diagnostic in &diagnostics {
+            verbose!("{}", diagnostic);
+        }
+        Ok(fs)
+    }
+
+    // logs are streamed rather than read whole
+    fn load(source: &Source) -> Result<Self::Input, LoadError> {
+        read_log(&source.name(), source.open()?, |diagnostic| verbose!("{}", diagnostic))
+    }
+
+    // total size of the directories of at most 100000
+    fn part1(fs: &Self::Input) -> u64 {
+        print(fs);
+        verbose!("{} directories, {} in all", fs.dirs().count(), fs.size(fs.root()));
+        fs.dirs()
+            .map(|dir| fs.size(dir))
+            .filter(|&size| size <= SMALL_DIR)
+            .fold(0, u64::saturating_add)
+    }
+
+    // size of the smallest directory which frees enough space for the update
+    fn part2(fs: &Self::Input) -> u64 {
+        let free = DISK_SIZE.saturating_sub(fs.size(fs.root()));
+        let needed = UPDATE_SIZE.saturating_sub(free);
+        verbose!("{} free, {} more needed", free, needed);
+        fs.dirs().map(|dir| fs.size(dir)).filter(|&size| size >= needed).min().unwrap_or(0)
+    }
+}
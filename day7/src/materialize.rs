@@ -0,0 +1,199 @@
+/*
+The filesystem made real, to be checked against tools such as du and tar:
+
+    write_dir       directories and files under a target directory, each
+                    file sparse but of its size, so taking no space to speak of
+    write_tar       a tar archive (POSIX ustar) of the same, with files of zeros
+    json            one JSON object per node, directories with their children:
+                    {"name":"/","type":"dir","size":584,"children":[
+                        {"name":"i","type":"file","size":584}]}
+    tree            a drawing of the tree as the tree command prints it:
+                    /  (584)
+                    └── i  (584)
+
+Names which would not stay inside the target (., .., or anything with
+a / in it) are refused.
+*/
+
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::Path;
+
+use common::json;
+
+use crate::{FileSystem, NodeId};
+
+const BLOCK: usize = 512;
+
+// a name which can be a file or directory of its own
+fn check_name(fs: &FileSystem, node: NodeId) -> io::Result<()> {
+    let name = fs.name(node);
+    if name.is_empty() || name == "." || name == ".." || name.contains(['/', '\0']) {
+        let message = format!("{}: cannot be written as a file", fs.path(node));
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, message));
+    }
+    Ok(())
+}
+
+// path of a node relative to the root, such as a/e/i
+fn relative(fs: &FileSystem, node: NodeId) -> String {
+    fs.path(node)[1..].to_string()
+}
+
+// create everything in the filesystem under the target directory
+pub fn write_dir(fs: &FileSystem, target: &Path) -> io::Result<()> {
+    fs::create_dir_all(target)?;
+    for node in fs.walk(fs.root()).skip(1) {
+        check_name(fs, node)?;
+        let path = target.join(relative(fs, node));
+        if fs.is_dir(node) {
+            fs::create_dir_all(path)?;
+        } else {
+            File::create(path)?.set_len(fs.size(node))?;
+        }
+    }
+    Ok(())
+}
+
+// write everything in the filesystem as a tar archive,
+// each directory before its entries
+pub fn write_tar(fs: &FileSystem, out: &mut impl Write) -> io::Result<()> {
+    let zeros = [0u8; 64 * BLOCK];
+    for node in fs.walk(fs.root()).skip(1) {
+        check_name(fs, node)?;
+        let mut path = relative(fs, node);
+        let size = if fs.is_dir(node) {
+            path.push('/');
+            0
+        } else {
+            fs.size(node)
+        };
+        out.write_all(&header(&path, fs.is_dir(node), size)?)?;
+        // the contents, padded to a whole number of blocks
+        let mut left = size.div_ceil(BLOCK as u64) * BLOCK as u64;
+        while left > 0 {
+            let len = left.min(zeros.len() as u64) as usize;
+            out.write_all(&zeros[..len])?;
+            left -= len as u64;
+        }
+    }
+    // the end of the archive
+    out.write_all(&[0; 2 * BLOCK])
+}
+
+// the header block of a tar entry
+fn header(path: &str, dir: bool, size: u64) -> io::Result<[u8; BLOCK]> {
+    let mut block = [0u8; BLOCK];
+    let (prefix, name) = split_path(path).ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidInput, format!("/{}: path too long for tar", path))
+    })?;
+    block[..name.len()].copy_from_slice(name.as_bytes());
+    block[345..345 + prefix.len()].copy_from_slice(prefix.as_bytes());
+    octal(&mut block[100..108], if dir { 0o755 } else { 0o644 });
+    octal(&mut block[108..116], 0);
+    octal(&mut block[116..124], 0);
+    if size < 8u64.pow(11) {
+        octal(&mut block[124..136], size);
+    } else {
+        // too big for octal: big-endian binary, marked by the top bit
+        block[124] = 0x80;
+        block[128..136].copy_from_slice(&size.to_be_bytes());
+    }
+    octal(&mut block[136..148], 0);
+    block[156] = if dir { b'5' } else { b'0' };
+    block[257..263].copy_from_slice(b"ustar\0");
+    block[263..265].copy_from_slice(b"00");
+    // the checksum is of the header with spaces for the checksum itself
+    block[148..156].copy_from_slice(b"        ");
+    let sum = block.iter().map(|&b| b as u64).sum::<u64>();
+    octal(&mut block[148..155], sum);
+    Ok(block)
+}
+
+// a number in octal, filling the field but for a closing NUL
+fn octal(field: &mut [u8], value: u64) {
+    let digits = format!("{:0width$o}", value, width = field.len() - 1);
+    field[..digits.len()].copy_from_slice(digits.as_bytes());
+    field[digits.len()] = 0;
+}
+
+// split a path into a prefix of at most 155 bytes and a name of at most
+// 100, at a /, unless it fits in the name alone
+fn split_path(path: &str) -> Option<(&str, &str)> {
+    if path.len() <= 100 {
+        return Some(("", path));
+    }
+    // a directory's closing / stays with its name
+    let search = path.strip_suffix('/').unwrap_or(path);
+    search
+        .match_indices('/')
+        .map(|(at, _)| (&path[..at], &path[at + 1..]))
+        .find(|(prefix, name)| prefix.len() <= 155 && name.len() <= 100)
+}
+
+// the filesystem as nested JSON objects, written without recursion
+// so that deep trees cannot overflow the stack
+pub fn json(fs: &FileSystem) -> String {
+    let mut text = String::new();
+    // the children still to write of each directory being written
+    let mut open = Vec::new();
+    let mut next = Some(fs.root());
+    loop {
+        if let Some(node) = next {
+            let kind = if fs.is_dir(node) { "dir" } else { "file" };
+            text += &format!(
+                "{{\"name\":{},\"type\":\"{}\",\"size\":{}",
+                json::string(fs.name(node)),
+                kind,
+                fs.size(node)
+            );
+            if fs.is_dir(node) {
+                text += ",\"children\":[";
+                open.push(fs.children(node));
+            } else {
+                text.push('}');
+            }
+        }
+        let Some(children) = open.last_mut() else {
+            return text;
+        };
+        next = children.next();
+        match next {
+            Some(_) if !text.ends_with('[') => text.push(','),
+            Some(_) => (),
+            None => {
+                open.pop();
+                text += "]}";
+            }
+        }
+    }
+}
+
+// the filesystem drawn as a tree, with sizes
+pub fn tree(fs: &FileSystem) -> String {
+    let mut text = format!("/  ({})\n", fs.size(fs.root()));
+    // the children still to draw of each directory being drawn,
+    // with the indent of their lines
+    let mut open = vec![(fs.children(fs.root()).peekable(), String::new())];
+    while let Some((children, indent)) = open.last_mut() {
+        let Some(child) = children.next() else {
+            open.pop();
+            continue;
+        };
+        let last = children.peek().is_none();
+        let slash = if fs.is_dir(child) { "/" } else { "" };
+        text += &format!(
+            "{}{}{}{}  ({})\n",
+            indent,
+            if last { "└── " } else { "├── " },
+            fs.name(child),
+            slash,
+            fs.size(child)
+        );
+        if fs.is_dir(child) {
+            let indent = format!("{}{}", indent, if last { "    " } else { "│   " });
+            open.push((fs.children(child).peekable(), indent));
+        }
+    }
+    text
+}
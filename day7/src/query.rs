@@ -0,0 +1,224 @@
+/*
+Queries over the filesystem, beyond the two asked by the puzzle.
+
+    size PATH                       size of a file, or of everything in a directory
+    du [PATH] [--depth N]           every directory below PATH, up to N levels down,
+                                    each after the directories in it, as du lists them
+    find [PATH] [--name GLOB] [--min SIZE] [--max SIZE] [--type f|d]
+                                    every file and directory below PATH which matches,
+                                    each directory before its entries
+    top N [PATH]                    the N largest directories below PATH
+
+Paths are taken from the root, with or without the leading /. A glob
+matches the name of a file or directory, where * stands for any run of
+characters and ? for any one. Sizes are inclusive.
+
+The answer to a query is a list of nodes, printed one per line with
+their sizes, directories ending in /:
+
+    94853   /a/
+    584     /a/e/
+*/
+
+use common::parse::Line;
+use common::ParseError;
+
+use crate::{FileSystem, NodeId};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    File,
+    Dir,
+}
+
+// what find looks for; anything, unless narrowed down
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Filter {
+    pub name: Option<String>,
+    pub min: Option<u64>,
+    pub max: Option<u64>,
+    pub kind: Option<Kind>,
+}
+
+impl Filter {
+    pub fn matches(&self, fs: &FileSystem, node: NodeId) -> bool {
+        let size = fs.size(node);
+        let kind = if fs.is_dir(node) { Kind::Dir } else { Kind::File };
+        self.name.as_ref().is_none_or(|pattern| glob(pattern, fs.name(node)))
+            && self.min.is_none_or(|min| size >= min)
+            && self.max.is_none_or(|max| size <= max)
+            && self.kind.is_none_or(|k| k == kind)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Query {
+    Size(String),
+    Du { path: String, depth: Option<usize> },
+    Find { path: String, filter: Filter },
+    Top { n: usize, path: String },
+}
+
+impl Query {
+    // read a query from one line of text
+    pub fn parse(text: &str) -> Result<Query, ParseError> {
+        let mut line = Line::new(1, text);
+        line.skip_spaces();
+        let start = line.pos();
+        let query = match line.word("a query `size`, `du`, `find` or `top`")? {
+            "size" => Query::Size(line.word("a path")?.to_string()),
+            "du" => {
+                let path = path(&mut line);
+                let mut depth = None;
+                while !line.at_end() {
+                    option(&mut line, &["--depth"])?;
+                    depth = Some(line.number()?);
+                    line.skip_spaces();
+                }
+                Query::Du { path, depth }
+            }
+            "find" => {
+                let path = path(&mut line);
+                let mut filter = Filter::default();
+                while !line.at_end() {
+                    match option(&mut line, &["--name", "--min", "--max", "--type"])? {
+                        "--name" => filter.name = Some(line.word("a name or glob")?.to_string()),
+                        "--min" => filter.min = Some(line.number()?),
+                        "--max" => filter.max = Some(line.number()?),
+                        _ => {
+                            line.skip_spaces();
+                            let at = line.pos();
+                            filter.kind = match line.word("`f` or `d`")? {
+                                "f" => Some(Kind::File),
+                                "d" => Some(Kind::Dir),
+                                _ => return Err(line.error_at(at, "`f` or `d`")),
+                            };
+                        }
+                    }
+                    line.skip_spaces();
+                }
+                Query::Find { path, filter }
+            }
+            "top" => {
+                let n = line.number()?;
+                Query::Top { n, path: path(&mut line) }
+            }
+            _ => return Err(line.error_at(start, "a query `size`, `du`, `find` or `top`")),
+        };
+        line.end()?;
+        Ok(query)
+    }
+
+    // the nodes answering the query, or an error naming a path not found
+    pub fn run(&self, fs: &FileSystem) -> Result<Vec<NodeId>, String> {
+        let lookup = |path: &str| fs.lookup(path).ok_or_else(|| format!("{}: no such file or directory", path));
+        let dir = |path: &str| match lookup(path)? {
+            node if fs.is_dir(node) => Ok(node),
+            _ => Err(format!("{}: not a directory", path)),
+        };
+        Ok(match self {
+            Query::Size(path) => vec![lookup(path)?],
+            Query::Du { path, depth } => du(fs, dir(path)?, depth.unwrap_or(usize::MAX)),
+            Query::Find { path, filter } => find(fs, dir(path)?, filter),
+            Query::Top { n, path } => top(fs, dir(path)?, *n),
+        })
+    }
+}
+
+// a path, if the next word is not an option; the root otherwise
+fn path(line: &mut Line) -> String {
+    line.skip_spaces();
+    if line.at_end() || line.rest().starts_with("--") {
+        return "/".to_string();
+    }
+    line.word("a path").unwrap_or("/").to_string()
+}
+
+// the next word, which must be one of the given options
+fn option<'a>(line: &mut Line<'a>, options: &[&str]) -> Result<&'a str, ParseError> {
+    let expected = options.iter().map(|o| format!("`{}`", o)).collect::<Vec<String>>().join(" or ");
+    line.skip_spaces();
+    let start = line.pos();
+    let word = line.word(&expected)?;
+    if !options.contains(&word) {
+        return Err(line.error_at(start, &expected));
+    }
+    Ok(word)
+}
+
+// the directory and those below it, down to the given depth,
+// each after the directories in it
+pub fn du(fs: &FileSystem, dir: NodeId, depth: usize) -> Vec<NodeId> {
+    let mut dirs = Vec::new();
+    let mut stack = vec![(dir, 0, false)];
+    while let Some((node, level, visited)) = stack.pop() {
+        if visited {
+            dirs.push(node);
+            continue;
+        }
+        stack.push((node, level, true));
+        if level < depth {
+            let below = fs.children(node).filter(|&child| fs.is_dir(child)).collect::<Vec<NodeId>>();
+            stack.extend(below.into_iter().rev().map(|child| (child, level + 1, false)));
+        }
+    }
+    dirs
+}
+
+// everything in the directory and below it which the filter matches
+pub fn find(fs: &FileSystem, dir: NodeId, filter: &Filter) -> Vec<NodeId> {
+    fs.walk(dir).filter(|&node| filter.matches(fs, node)).collect()
+}
+
+// the n largest directories from the given one down,
+// those of the same size by path
+pub fn top(fs: &FileSystem, dir: NodeId, n: usize) -> Vec<NodeId> {
+    let mut dirs = fs.walk(dir).filter(|&node| fs.is_dir(node)).collect::<Vec<NodeId>>();
+    dirs.sort_by_cached_key(|&node| (std::cmp::Reverse(fs.size(node)), fs.path(node)));
+    dirs.truncate(n);
+    dirs
+}
+
+// whether a name matches a pattern with * and ?
+pub fn glob(pattern: &str, name: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<char>>();
+    let name = name.chars().collect::<Vec<char>>();
+    let (mut p, mut n) = (0, 0);
+    // where the last * was, and how much of the name it has taken
+    let mut star = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                // let the * take one more character
+                Some((sp, sn)) => {
+                    star = Some((sp, sn + 1));
+                    p = sp + 1;
+                    n = sn + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+// one line per node: its size and its path, directories ending in /
+pub fn format(fs: &FileSystem, nodes: &[NodeId]) -> String {
+    let mut text = String::new();
+    for &node in nodes {
+        let mut path = fs.path(node);
+        if fs.is_dir(node) && node != fs.root() {
+            path.push('/');
+        }
+        text += &format!("{}\t{}\n", fs.size(node), path);
+    }
+    text
+}
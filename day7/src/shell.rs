@@ -0,0 +1,281 @@
+/*
+Interpreter for the terminal log, building the filesystem as it goes.
+
+Commands are `cd <path>` and `ls`. Paths are made of names separated by
+/, starting from the root if they start with /, where .. is the parent
+directory and . the current one:
+
+    $ cd /
+    $ cd a/e
+    $ cd ../../d
+
+A directory which has not been listed yet is created on the way, and
+reported as a diagnostic, as the log may start partway through a
+session. Only a name which is already a file cannot be followed.
+
+A directory may be listed more than once, and the latest listing is
+taken to be the truth: files which have changed size are resized,
+entries which have turned from files into directories or the other way
+round are replaced, and entries which are no longer there are removed,
+along with everything in them. Each change is reported as a diagnostic,
+as is any entry listed twice in the same listing.
+*/
+
+use std::collections::HashSet;
+use std::fmt;
+
+use common::parse::Line;
+use common::{trace, ParseError};
+
+use crate::{FileSystem, NodeId};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Change {
+    // appeared when its directory was listed again
+    Added,
+    // gone when its directory was listed again
+    Removed,
+    Resized { from: u64, to: u64 },
+    // a file which is now a directory, or the other way round
+    Replaced,
+    // listed twice in the same listing
+    Repeated,
+    // a directory changed into before it was listed
+    Implied,
+}
+
+// a change to the filesystem found on a line of the log
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub line: usize,
+    pub path: String,
+    pub change: Change,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {} ", self.line, self.path)?;
+        match self.change {
+            Change::Added => write!(f, "added"),
+            Change::Removed => write!(f, "removed"),
+            Change::Resized { from, to } => write!(f, "resized from {} to {}", from, to),
+            Change::Replaced => write!(f, "replaced"),
+            Change::Repeated => write!(f, "listed twice"),
+            Change::Implied => write!(f, "entered before being listed"),
+        }
+    }
+}
+
+// the directory being listed by the last ls
+struct Listing {
+    dir: NodeId,
+    // line of the ls command
+    line: usize,
+    // whether the directory has been listed before
+    again: bool,
+    // names listed so far
+    seen: HashSet<String>,
+}
+
+pub struct Shell {
+    fs: FileSystem,
+    cwd: NodeId,
+    // None unless the last command was ls
+    listing: Option<Listing>,
+    listed: HashSet<NodeId>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Shell {
+    // a shell in the root of an empty filesystem
+    pub fn new() -> Shell {
+        let fs = FileSystem::new();
+        Shell {
+            cwd: fs.root(),
+            fs,
+            listing: None,
+            listed: HashSet::new(),
+            diagnostics: Vec::new(),
+        }
+    }
+
+    pub fn fs(&self) -> &FileSystem {
+        &self.fs
+    }
+
+    pub fn cwd(&self) -> NodeId {
+        self.cwd
+    }
+
+    // interpret one line of the log: a command, or output of ls
+    pub fn run(&mut self, line: &mut Line) -> Result<(), ParseError> {
+        if line.rest().starts_with('$') {
+            line.literal("$")?;
+            self.end_listing();
+            self.command(line)
+        } else {
+            self.output(line)
+        }
+    }
+
+    // the changes found since last asked
+    pub fn drain(&mut self) -> impl Iterator<Item = Diagnostic> + '_ {
+        self.diagnostics.drain(..)
+    }
+
+    // the filesystem as the log left it, and the changes found on the way
+    pub fn finish(mut self) -> (FileSystem, Vec<Diagnostic>) {
+        self.end_listing();
+        (self.fs, self.diagnostics)
+    }
+
+    // `cd <path>` or `ls`
+    fn command(&mut self, line: &mut Line) -> Result<(), ParseError> {
+        line.skip_spaces();
+        let start = line.pos();
+        match line.word("a command `cd` or `ls`")? {
+            "cd" => self.cd(line),
+            "ls" => {
+                line.end()?;
+                trace!("cmd: ls");
+                self.listing = Some(Listing {
+                    dir: self.cwd,
+                    line: line.number,
+                    again: !self.listed.insert(self.cwd),
+                    seen: HashSet::new(),
+                });
+                Ok(())
+            }
+            _ => Err(line.error_at(start, "a command `cd` or `ls`")),
+        }
+    }
+
+    // change directory one name of the path at a time, creating any
+    // directory not seen yet, and pointing at the name which can't be
+    // followed if any
+    fn cd(&mut self, line: &mut Line) -> Result<(), ParseError> {
+        line.skip_spaces();
+        let start = line.pos();
+        let path = line.word("a directory")?;
+        line.end()?;
+        trace!("cmd: cd {}", path);
+        let mut dir = if path.starts_with('/') { self.fs.root() } else { self.cwd };
+        let mut offset = start;
+        for name in path.split('/') {
+            dir = match name {
+                "" | "." => dir,
+                ".." => self
+                    .fs
+                    .parent(dir)
+                    .ok_or_else(|| line.error_at(offset, "a directory below the root"))?,
+                _ => match self.fs.child(dir, name) {
+                    Some(child) if self.fs.is_dir(child) => child,
+                    Some(_) => return Err(line.error_at(offset, "a directory rather than a file")),
+                    None => {
+                        self.report(line.number, dir, name, Change::Implied);
+                        self.fs.add_dir(dir, name)
+                    }
+                },
+            };
+            offset += name.len() + 1;
+        }
+        self.cwd = dir;
+        Ok(())
+    }
+
+    // `dir <name>` or `<size> <name>`, reconciled with what is already there
+    fn output(&mut self, line: &mut Line) -> Result<(), ParseError> {
+        let Some(listing) = &mut self.listing else {
+            return Err(line.error("a command starting with `$`"));
+        };
+        let size = if line.rest().starts_with("dir") {
+            line.keyword("dir")?;
+            None
+        } else {
+            Some(line.number::<u64>()?)
+        };
+        let name = line.word("a file name")?;
+        line.end()?;
+        let dir = listing.dir;
+        let mut changes = Vec::new();
+        if !listing.seen.insert(name.to_string()) {
+            changes.push(Change::Repeated);
+        }
+        let again = listing.again;
+        match (self.fs.child(dir, name), size) {
+            (None, _) => {
+                if again {
+                    changes.push(Change::Added);
+                }
+                self.add(dir, name, size);
+            }
+            (Some(old), Some(size)) if !self.fs.is_dir(old) => {
+                let from = self.fs.size(old);
+                if from != size {
+                    changes.push(Change::Resized { from, to: size });
+                    self.fs.resize(old, size);
+                }
+            }
+            (Some(old), None) if self.fs.is_dir(old) => (),
+            (Some(old), _) => {
+                changes.push(Change::Replaced);
+                self.forget(old);
+                self.add(dir, name, size);
+            }
+        }
+        for change in changes {
+            self.report(line.number, dir, name, change);
+        }
+        Ok(())
+    }
+
+    fn add(&mut self, dir: NodeId, name: &str, size: Option<u64>) {
+        match size {
+            Some(size) => self.fs.add_file(dir, name, size),
+            None => self.fs.add_dir(dir, name),
+        };
+    }
+
+    // remove whatever a directory listed again no longer has
+    fn end_listing(&mut self) {
+        let Some(listing) = self.listing.take() else {
+            return;
+        };
+        if !listing.again {
+            return;
+        }
+        let gone = self
+            .fs
+            .children(listing.dir)
+            .filter(|&child| !listing.seen.contains(self.fs.name(child)))
+            .collect::<Vec<NodeId>>();
+        for child in gone {
+            let name = self.fs.name(child).to_string();
+            self.report(listing.line, listing.dir, &name, Change::Removed);
+            self.forget(child);
+        }
+    }
+
+    // remove a node, and forget having listed any directory removed
+    // so that a directory added in its place is new
+    fn forget(&mut self, node: NodeId) {
+        for gone in self.fs.remove(node) {
+            self.listed.remove(&gone);
+        }
+    }
+
+    fn report(&mut self, line: usize, dir: NodeId, name: &str, change: Change) {
+        let path = match self.fs.parent(dir) {
+            None => format!("/{}", name),
+            Some(_) => format!("{}/{}", self.fs.path(dir), name),
+        };
+        trace!("line {}: {} {:?}", line, path, change);
+        self.diagnostics.push(Diagnostic { line, path, change });
+    }
+}
+
+impl Default for Shell {
+    fn default() -> Shell {
+        Shell::new()
+    }
+}
@@ -0,0 +1,240 @@
+/*
+The filesystem as a tree kept in an arena.
+
+Every file and directory is a node in one vector, and is referred to by
+its index there, a NodeId. Nodes know their parent, so going up a level
+is a lookup, and directories map the names of their entries to nodes.
+
+Each directory also keeps the total size of everything below it, which
+is brought up to date all the way up to the root whenever a file is
+added, resized or removed, so the size of any directory can be had at
+once. The places of removed nodes are taken by those added after, so
+the arena never holds more than the biggest the tree has been.
+
+    let mut fs = FileSystem::new();
+    let a = fs.add_dir(fs.root(), "a");
+    fs.add_file(a, "f", 100);
+    assert_eq!(fs.size(fs.root()), 100);
+*/
+
+use std::collections::BTreeMap;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(usize);
+
+struct Node {
+    name: String,
+    parent: Option<NodeId>,
+    // size of a file, or total size of a directory's contents
+    size: u64,
+    // entries of a directory by name, or None for a file
+    children: Option<BTreeMap<String, NodeId>>,
+    removed: bool,
+}
+
+pub struct FileSystem {
+    // the root is always the first node
+    nodes: Vec<Node>,
+    // places of removed nodes, free to be taken
+    free: Vec<NodeId>,
+}
+
+impl FileSystem {
+    // an empty root directory
+    pub fn new() -> FileSystem {
+        FileSystem {
+            nodes: vec![Node {
+                name: "/".to_string(),
+                parent: None,
+                size: 0,
+                children: Some(BTreeMap::new()),
+                removed: false,
+            }],
+            free: Vec::new(),
+        }
+    }
+
+    pub fn root(&self) -> NodeId {
+        NodeId(0)
+    }
+
+    // number of files and directories, counting the root
+    pub fn len(&self) -> usize {
+        self.nodes.len() - self.free.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 1
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.nodes[id.0].name
+    }
+
+    // the directory holding a node, or None for the root
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.nodes[id.0].parent
+    }
+
+    // size of a file, or of everything in a directory
+    pub fn size(&self, id: NodeId) -> u64 {
+        self.nodes[id.0].size
+    }
+
+    pub fn is_dir(&self, id: NodeId) -> bool {
+        self.nodes[id.0].children.is_some()
+    }
+
+    // the entry of a directory with the given name
+    pub fn child(&self, dir: NodeId, name: &str) -> Option<NodeId> {
+        self.nodes[dir.0].children.as_ref()?.get(name).copied()
+    }
+
+    // entries of a directory, by name
+    pub fn children(&self, dir: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.nodes[dir.0].children.iter().flat_map(|children| children.values().copied())
+    }
+
+    // every directory, the root first
+    pub fn dirs(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.live().filter(|&id| self.is_dir(id))
+    }
+
+    // every file
+    pub fn files(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.live().filter(|&id| !self.is_dir(id))
+    }
+
+    fn live(&self) -> impl Iterator<Item = NodeId> + '_ {
+        (0..self.nodes.len()).map(NodeId).filter(|&id| !self.nodes[id.0].removed)
+    }
+
+    // every node in a directory and below it, each directory before its entries
+    pub fn walk(&self, dir: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        let mut stack = vec![dir];
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            let children = self.children(node).collect::<Vec<NodeId>>();
+            stack.extend(children.into_iter().rev());
+            Some(node)
+        })
+    }
+
+    // the node at a path such as /a/e/i, taken from the root even without
+    // the leading /, where .. is the parent directory and . the same one
+    pub fn lookup(&self, path: &str) -> Option<NodeId> {
+        let mut node = self.root();
+        for name in path.split('/') {
+            node = match name {
+                "" | "." => node,
+                ".." => self.parent(node)?,
+                _ => self.child(node, name)?,
+            };
+        }
+        Some(node)
+    }
+
+    // absolute path of a node, such as /a/e/i
+    pub fn path(&self, id: NodeId) -> String {
+        let mut names = Vec::new();
+        let mut node = id;
+        while let Some(parent) = self.parent(node) {
+            names.push(self.name(node));
+            node = parent;
+        }
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+
+    // add a directory to a directory, or find the entry already there
+    pub fn add_dir(&mut self, dir: NodeId, name: &str) -> NodeId {
+        self.add(dir, name, 0, Some(BTreeMap::new()))
+    }
+
+    // add a file to a directory, counting its size in every directory
+    // above it, or find the entry already there
+    pub fn add_file(&mut self, dir: NodeId, name: &str, size: u64) -> NodeId {
+        if let Some(id) = self.child(dir, name) {
+            return id;
+        }
+        let id = self.add(dir, name, size, None);
+        self.update_sizes(dir, 0, size);
+        id
+    }
+
+    // change the size of a file, and of every directory above it
+    pub fn resize(&mut self, file: NodeId, size: u64) {
+        assert!(!self.is_dir(file), "{} is a directory", self.path(file));
+        let was = self.size(file);
+        self.nodes[file.0].size = size;
+        if let Some(dir) = self.parent(file) {
+            self.update_sizes(dir, was, size);
+        }
+    }
+
+    // take a file or directory and everything in it out of the tree,
+    // returning all the nodes removed, which may be reused
+    pub fn remove(&mut self, id: NodeId) -> Vec<NodeId> {
+        let dir = self.parent(id).expect("the root can't be removed");
+        let name = self.nodes[id.0].name.clone();
+        if let Some(children) = &mut self.nodes[dir.0].children {
+            children.remove(&name);
+        }
+        self.update_sizes(dir, self.size(id), 0);
+        let start = self.free.len();
+        let mut stack = vec![id];
+        while let Some(gone) = stack.pop() {
+            stack.extend(self.children(gone));
+            let node = &mut self.nodes[gone.0];
+            node.removed = true;
+            node.children = None;
+            node.name = String::new();
+            self.free.push(gone);
+        }
+        self.free[start..].to_vec()
+    }
+
+    // replace a size which was counted in the directory and those above it
+    fn update_sizes(&mut self, dir: NodeId, was: u64, size: u64) {
+        let mut above = Some(dir);
+        while let Some(dir) = above {
+            let node = &mut self.nodes[dir.0];
+            node.size = node.size.saturating_sub(was).saturating_add(size);
+            above = node.parent;
+        }
+    }
+
+    fn add(&mut self, dir: NodeId, name: &str, size: u64, children: Option<BTreeMap<String, NodeId>>) -> NodeId {
+        assert!(self.is_dir(dir), "{} is not a directory", self.path(dir));
+        if let Some(id) = self.child(dir, name) {
+            return id;
+        }
+        let node = Node {
+            name: name.to_string(),
+            parent: Some(dir),
+            size,
+            children,
+            removed: false,
+        };
+        let id = match self.free.pop() {
+            Some(id) => {
+                self.nodes[id.0] = node;
+                id
+            }
+            None => {
+                self.nodes.push(node);
+                NodeId(self.nodes.len() - 1)
+            }
+        };
+        if let Some(children) = &mut self.nodes[dir.0].children {
+            children.insert(name.to_string(), id);
+        }
+        id
+    }
+}
+
+impl Default for FileSystem {
+    fn default() -> FileSystem {
+        FileSystem::new()
+    }
+}
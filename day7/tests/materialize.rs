@@ -0,0 +1,127 @@
+use std::path::Path;
+
+use day7::materialize::{json, tree, write_dir, write_tar};
+use day7::{interpret, FileSystem};
+
+const LOG: &str = "\
+$ ls
+dir a
+14848514 b.txt
+$ cd a
+$ ls
+dir e
+29116 f
+$ cd e
+$ ls
+584 i
+";
+
+// total size of the files under a directory on disk, as du -b counts them
+fn du(path: &Path) -> u64 {
+    let meta = std::fs::metadata(path).unwrap();
+    if !meta.is_dir() {
+        return meta.len();
+    }
+    std::fs::read_dir(path).unwrap().map(|entry| du(&entry.unwrap().path())).sum()
+}
+
+#[test]
+fn directories_on_disk_have_the_sizes_of_the_log() {
+    let (fs, _) = interpret(LOG).unwrap();
+    let target = std::env::temp_dir().join(format!("day7-materialize-{}", std::process::id()));
+    write_dir(&fs, &target).unwrap();
+    for dir in fs.dirs() {
+        assert_eq!(du(&target.join(&fs.path(dir)[1..])), fs.size(dir), "{}", fs.path(dir));
+    }
+    std::fs::remove_dir_all(&target).unwrap();
+
+    let (fs, _) = interpret("$ ls\ndir ..\n").unwrap();
+    let err = write_dir(&fs, &target).unwrap_err();
+    assert_eq!(err.to_string(), "/..: cannot be written as a file");
+    let _ = std::fs::remove_dir_all(&target);
+}
+
+// the name, type and size of each entry in a tar archive,
+// checking the headers' checksums
+fn entries(tar: &[u8]) -> Vec<(String, u8, u64)> {
+    let field = |block: &[u8], from: usize, to: usize| {
+        let text = &block[from..to];
+        let len = text.iter().position(|&b| b == 0).unwrap_or(text.len());
+        String::from_utf8(text[..len].to_vec()).unwrap()
+    };
+    let mut entries = Vec::new();
+    let mut pos = 0;
+    while tar[pos..pos + 512].iter().any(|&b| b != 0) {
+        let block = &tar[pos..pos + 512];
+        let sum = block.iter().enumerate().map(|(i, &b)| if (148..156).contains(&i) { 32 } else { b as u64 }).sum::<u64>();
+        assert_eq!(u64::from_str_radix(&field(block, 148, 155), 8).unwrap(), sum);
+        assert_eq!(&block[257..265], b"ustar\x0000");
+        let size = u64::from_str_radix(&field(block, 124, 135), 8).unwrap();
+        entries.push((field(block, 0, 100), block[156], size));
+        pos += 512 + size.div_ceil(512) as usize * 512;
+    }
+    assert_eq!(tar.len(), pos + 1024);
+    entries
+}
+
+#[test]
+fn tar_archives_list_each_directory_before_its_entries() {
+    let (fs, _) = interpret(LOG).unwrap();
+    let mut tar = Vec::new();
+    write_tar(&fs, &mut tar).unwrap();
+    assert_eq!(
+        entries(&tar),
+        vec![
+            ("a/".to_string(), b'5', 0),
+            ("a/e/".to_string(), b'5', 0),
+            ("a/e/i".to_string(), b'0', 584),
+            ("a/f".to_string(), b'0', 29116),
+            ("b.txt".to_string(), b'0', 14848514),
+        ]
+    );
+}
+
+#[test]
+fn dumps_show_the_whole_tree() {
+    let mut fs = FileSystem::new();
+    let a = fs.add_dir(fs.root(), "a \"quoted\"");
+    fs.add_file(a, "i", 584);
+    fs.add_file(fs.root(), "b", 10);
+    assert_eq!(
+        json(&fs),
+        concat!(
+            r#"{"name":"/","type":"dir","size":594,"children":["#,
+            r#"{"name":"a \"quoted\"","type":"dir","size":584,"children":[{"name":"i","type":"file","size":584}]},"#,
+            r#"{"name":"b","type":"file","size":10}]}"#
+        )
+    );
+    assert_eq!(tree(&fs), "/  (594)\n├── a \"quoted\"/  (584)\n│   └── i  (584)\n└── b  (10)\n");
+}
+
+// a directory in a directory, and so on, with a file at the bottom
+fn nested(depth: usize) -> FileSystem {
+    let mut fs = FileSystem::new();
+    let mut dir = fs.root();
+    for _ in 0..depth {
+        dir = fs.add_dir(dir, "d");
+    }
+    fs.add_file(dir, "new\nline", 1);
+    fs.add_dir(fs.root(), "empty");
+    fs
+}
+
+#[test]
+fn deep_trees_do_not_overflow_the_stack() {
+    let text = json(&nested(100_000));
+    assert!(text.starts_with(r#"{"name":"/","type":"dir","size":1,"children":[{"name":"d","#));
+    let end = format!(
+        r#"{{"name":"new\nline","type":"file","size":1}}{},{{"name":"empty","type":"dir","size":0,"children":[]}}]}}"#,
+        "]}".repeat(100_000)
+    );
+    assert!(text.ends_with(&end));
+    // each line is indented by its depth, so the drawing grows as its square,
+    // and the newline in the file's name breaks its line in two
+    let tree = tree(&nested(5_000));
+    assert_eq!(tree.lines().count(), 5_004);
+    assert!(tree.ends_with("  └── new\nline  (1)\n└── empty/  (0)\n"));
+}
@@ -0,0 +1,82 @@
+use common::Solution;
+use day7::query::{format, glob, Filter, Kind, Query};
+use day7::{Day7, FileSystem};
+
+const EXAMPLE: &str = "\
+$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k
+";
+
+fn answer(fs: &FileSystem, query: &str) -> String {
+    format(fs, &Query::parse(query).unwrap().run(fs).unwrap())
+}
+
+#[test]
+fn queries_read_their_options() {
+    assert_eq!(Query::parse("size /a/e").unwrap(), Query::Size("/a/e".to_string()));
+    assert_eq!(
+        Query::parse(" du  --depth 2").unwrap(),
+        Query::Du { path: "/".to_string(), depth: Some(2) }
+    );
+    let filter = Filter { name: Some("*.txt".to_string()), min: Some(10), max: None, kind: Some(Kind::File) };
+    assert_eq!(
+        Query::parse("find a --name *.txt --type f --min 10").unwrap(),
+        Query::Find { path: "a".to_string(), filter }
+    );
+    assert_eq!(Query::parse("top 3 d").unwrap(), Query::Top { n: 3, path: "d".to_string() });
+
+    let column = |query| Query::parse(query).unwrap_err().column;
+    assert_eq!(column("ls"), 1);
+    assert_eq!(column("du / --depth x"), 14);
+    assert_eq!(column("find --type x"), 13);
+    assert_eq!(column("top"), 4);
+    assert_eq!(column("size a b"), 8);
+}
+
+#[test]
+fn queries_answer_from_the_tree() {
+    let fs = Day7::parse(EXAMPLE).unwrap();
+    assert_eq!(answer(&fs, "size a/e/i"), "584\t/a/e/i\n");
+    assert_eq!(answer(&fs, "du"), "584\t/a/e/\n94853\t/a/\n24933642\t/d/\n48381165\t/\n");
+    assert_eq!(answer(&fs, "du /a --depth 0"), "94853\t/a/\n");
+    assert_eq!(answer(&fs, "find --name d* --max 10000000"), "5626152\t/d/d.ext\n8033020\t/d/d.log\n");
+    assert_eq!(answer(&fs, "find --type d --max 100000"), "94853\t/a/\n584\t/a/e/\n");
+    assert_eq!(answer(&fs, "top 2 a"), "94853\t/a/\n584\t/a/e/\n");
+
+    let error = |query| Query::parse(query).unwrap().run(&fs).unwrap_err();
+    assert_eq!(error("size /x"), "/x: no such file or directory");
+    assert_eq!(error("du b.txt"), "b.txt: not a directory");
+}
+
+#[test]
+fn globs_match_whole_names() {
+    assert!(glob("*", ""));
+    assert!(glob("*.txt", "b.txt"));
+    assert!(!glob("*.txt", "b.txt.gz"));
+    assert!(glob("?.*", "d.log"));
+    assert!(!glob("?", "ab"));
+    assert!(glob("a*b*c", "aXbYbZc"));
+    assert!(!glob("a*b*c", "aXbYbZ"));
+    assert!(glob("h.lst", "h.lst"));
+}
@@ -0,0 +1,116 @@
+use day7::{interpret, Change, Diagnostic};
+
+fn error_at(log: &str) -> (usize, usize) {
+    let err = interpret(log).err().expect("expected a parse error");
+    (err.line, err.column)
+}
+
+#[test]
+fn paths_can_have_several_names() {
+    let log = "\
+$ cd /
+$ ls
+dir a
+$ cd a
+$ ls
+dir e
+$ cd e
+$ ls
+dir x
+$ cd /a/e/x
+$ ls
+10 f
+$ cd ../../..
+$ ls
+dir a
+20 g
+$ cd a/./e/
+$ ls
+dir x
+30 h
+";
+    let (fs, diagnostics) = interpret(log).unwrap();
+    // the root and /a/e were listed again, with a file more
+    let paths = diagnostics.iter().map(|d| (d.path.as_str(), d.change)).collect::<Vec<_>>();
+    assert_eq!(paths, vec![("/g", Change::Added), ("/a/e/h", Change::Added)]);
+    let sizes = fs.dirs().map(|dir| (fs.path(dir), fs.size(dir))).collect::<Vec<_>>();
+    assert_eq!(
+        sizes,
+        vec![
+            ("/".to_string(), 60),
+            ("/a".to_string(), 40),
+            ("/a/e".to_string(), 40),
+            ("/a/e/x".to_string(), 10),
+        ]
+    );
+}
+
+#[test]
+fn directories_can_be_entered_before_they_are_listed() {
+    let (fs, diagnostics) = interpret("$ cd /\n$ cd a\n$ ls\n10 f\n$ cd /b/c\n$ ls\n20 g\n").unwrap();
+    let implied = |line, path: &str| Diagnostic { line, path: path.to_string(), change: Change::Implied };
+    assert_eq!(diagnostics, vec![implied(2, "/a"), implied(5, "/b"), implied(5, "/b/c")]);
+    assert_eq!(diagnostics[0].to_string(), "line 2: /a entered before being listed");
+    let sizes = fs.dirs().map(|dir| (fs.path(dir), fs.size(dir))).collect::<Vec<_>>();
+    assert_eq!(
+        sizes,
+        vec![
+            ("/".to_string(), 30),
+            ("/a".to_string(), 10),
+            ("/b".to_string(), 20),
+            ("/b/c".to_string(), 20),
+        ]
+    );
+}
+
+#[test]
+fn bad_directories_are_errors_at_the_name() {
+    assert_eq!(error_at("$ cd ..\n"), (1, 6));
+    assert_eq!(error_at("$ cd /\n$ ls\ndir a\n$ cd a/../..\n"), (4, 11));
+    assert_eq!(error_at("$ ls\ndir a\n10 b\n$ cd a/../b/c\n"), (4, 11));
+    assert_eq!(error_at("$ ls\n10 a\n$ cd a\n"), (3, 6));
+    assert_eq!(error_at("$ cd\n"), (1, 5));
+}
+
+#[test]
+fn listing_again_reconciles_the_entries() {
+    let log = "\
+$ ls
+dir a
+dir b
+10 c
+20 d
+$ cd a
+$ ls
+5 x
+$ cd /
+$ ls
+dir a
+10 b
+30 c
+dir d
+40 e
+40 e
+";
+    let (fs, diagnostics) = interpret(log).unwrap();
+    let diagnostic = |line, path: &str, change| Diagnostic { line, path: path.to_string(), change };
+    assert_eq!(
+        diagnostics,
+        vec![
+            diagnostic(12, "/b", Change::Replaced),
+            diagnostic(13, "/c", Change::Resized { from: 10, to: 30 }),
+            diagnostic(14, "/d", Change::Replaced),
+            diagnostic(15, "/e", Change::Added),
+            diagnostic(16, "/e", Change::Repeated),
+        ]
+    );
+    assert_eq!(fs.size(fs.root()), 5 + 10 + 30 + 40);
+    assert_eq!(diagnostics[1].to_string(), "line 13: /c resized from 10 to 30");
+
+    let (fs, diagnostics) = interpret("$ ls\ndir a\n10 b\n$ cd a\n$ ls\n5 x\n$ cd ..\n$ ls\n").unwrap();
+    assert_eq!(
+        diagnostics,
+        vec![diagnostic(8, "/a", Change::Removed), diagnostic(8, "/b", Change::Removed)]
+    );
+    assert_eq!((fs.size(fs.root()), fs.len()), (0, 1));
+}
@@ -0,0 +1,84 @@
+use std::io::{self, BufRead, Read};
+
+use common::{LoadError, Solution, Source};
+use day7::{interpret, read_log, Day7};
+
+const LOG: &str = "\
+$ cd /
+$ ls
+dir a
+10 b
+$ cd a
+$ ls
+20 c
+$ cd /
+$ ls
+dir a
+15 b
+";
+
+#[test]
+fn streaming_gives_the_same_tree_and_changes() {
+    let (fs, diagnostics) = interpret(LOG).unwrap();
+    let mut streamed = Vec::new();
+    let crlf = LOG.replace('\n', "\r\n");
+    let fs2 = read_log("log", crlf.as_bytes(), |diagnostic| streamed.push(diagnostic)).unwrap();
+    assert_eq!(streamed, diagnostics);
+    let sizes = |fs: &day7::FileSystem| fs.dirs().map(|dir| (fs.path(dir), fs.size(dir))).collect::<Vec<_>>();
+    assert_eq!(sizes(&fs2), sizes(&fs));
+    assert_eq!(Day7::part1(&Day7::load(&Source::text(LOG)).unwrap()), 35 + 20);
+}
+
+#[test]
+fn streaming_errors_name_the_log() {
+    match read_log("log", "$ ls\n10 b\n$ cd b\n".as_bytes(), |_| ()) {
+        Err(LoadError::Parse(err)) => assert_eq!((err.file.as_deref(), err.line, err.column), (Some("log"), 3, 6)),
+        _ => panic!("expected a parse error"),
+    }
+    match read_log("log", &b"$ ls\n\xff\n"[..], |_| ()) {
+        Err(LoadError::Io(name, _)) => assert_eq!(name, "log"),
+        _ => panic!("expected a read error"),
+    }
+}
+
+// a log which is written as it is read, listing the same directory
+// over and over with a different file in it each time
+struct Churn {
+    listings: usize,
+    buffer: Vec<u8>,
+    pos: usize,
+}
+
+impl Read for Churn {
+    fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+        let len = self.fill_buf()?.read(out)?;
+        self.consume(len);
+        Ok(len)
+    }
+}
+
+impl BufRead for Churn {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.pos == self.buffer.len() && self.listings > 0 {
+            self.buffer = format!("$ ls\n{} f{}\n", self.listings, self.listings).into_bytes();
+            self.pos = 0;
+            self.listings -= 1;
+        }
+        Ok(&self.buffer[self.pos..])
+    }
+
+    fn consume(&mut self, len: usize) {
+        self.pos += len;
+    }
+}
+
+#[test]
+fn long_logs_keep_only_the_tree() {
+    let churn = Churn { listings: 100000, buffer: Vec::new(), pos: 0 };
+    let mut changes = 0;
+    let fs = read_log("churn", churn, |_| changes += 1).unwrap();
+    // each listing but the first adds one file and removes the one before
+    assert_eq!(changes, 2 * 99999);
+    assert_eq!(fs.len(), 2);
+    assert_eq!(fs.size(fs.root()), 1);
+}
@@ -0,0 +1,77 @@
+use common::Solution;
+use day7::{Day7, FileSystem};
+
+const EXAMPLE: &str = "\
+$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k
+";
+
+#[test]
+fn sizes_are_kept_up_to_date_on_insert() {
+    let mut fs = FileSystem::new();
+    let a = fs.add_dir(fs.root(), "a");
+    let e = fs.add_dir(a, "e");
+    fs.add_file(e, "i", 584);
+    assert_eq!((fs.size(e), fs.size(a), fs.size(fs.root())), (584, 584, 584));
+    fs.add_file(a, "f", 29116);
+    assert_eq!((fs.size(e), fs.size(a), fs.size(fs.root())), (584, 29700, 29700));
+    // adding an entry again finds the one already there
+    assert_eq!(fs.add_file(a, "f", 1), fs.child(a, "f").unwrap());
+    assert_eq!(fs.size(a), 29700);
+    assert_eq!(fs.add_dir(a, "e"), e);
+}
+
+#[test]
+fn nodes_know_their_parents_and_paths() {
+    let mut fs = FileSystem::new();
+    let a = fs.add_dir(fs.root(), "a");
+    let e = fs.add_dir(a, "e");
+    let i = fs.add_file(e, "i", 584);
+    assert_eq!(fs.parent(i), Some(e));
+    assert_eq!(fs.parent(e), Some(a));
+    assert_eq!(fs.parent(fs.root()), None);
+    assert_eq!(fs.path(i), "/a/e/i");
+    assert_eq!(fs.path(fs.root()), "/");
+    assert_eq!(fs.name(e), "e");
+    assert!(fs.is_dir(e) && !fs.is_dir(i));
+    assert_eq!(fs.child(i, "x"), None);
+}
+
+#[test]
+fn every_directory_of_the_example_has_its_size() {
+    let fs = Day7::parse(EXAMPLE).unwrap();
+    let sizes = fs.dirs().map(|dir| (fs.path(dir), fs.size(dir))).collect::<Vec<_>>();
+    assert_eq!(
+        sizes,
+        vec![
+            ("/".to_string(), 48381165),
+            ("/a".to_string(), 94853),
+            ("/d".to_string(), 24933642),
+            ("/a/e".to_string(), 584),
+        ]
+    );
+    assert_eq!(fs.files().count(), 10);
+    let names = fs.children(fs.root()).map(|node| fs.name(node)).collect::<Vec<_>>();
+    assert_eq!(names, vec!["a", "b.txt", "c.dat", "d"]);
+}
@@ -0,0 +1,24 @@
+// random grids of tree heights, as wide as the size and
+// anything from one row to as many rows as columns
+
+use common::generate::{Generator, Rng, StdRng};
+
+use grid::Grid;
+
+use crate::Day8;
+
+impl Generator for Day8 {
+    // number of columns
+    const DEFAULT_SIZE: usize = 99;
+
+    fn generate(rng: &mut StdRng, size: usize) -> Grid<u8> {
+        let height = if size == 0 { 0 } else { rng.gen_range(1..=size) };
+        Grid::from_fn(size, height, |_, _| rng.gen_range(0..=9))
+    }
+
+    fn to_text(grid: &Grid<u8>) -> String {
+        grid.rows()
+            .map(|row| row.iter().map(|height| height.to_string()).collect::<String>() + "\n")
+            .collect()
+    }
+}
@@ -0,0 +1,207 @@
+
+/*
+The expedition comes across a peculiar patch of tall trees all planted carefully in a grid. 
+The Elves explain that a previous expedition planted these trees as a reforestation effort. 
+Now, they're curious if this would be a good location for a tree house.
+
+First, determine whether there is enough tree cover here to keep a tree house hidden. 
+To do this, you need to count the number of trees that are visible from outside the grid 
+when looking directly along a row or column.
+
+The Elves have already launched a quadcopter to generate a map with the height of each 
+tree (your puzzle input). For example:
+
+30373
+25512
+65332
+33549
+35390
+
+Each tree is represented as a single digit whose value is its height, where 0 is the 
+shortest and 9 is the tallest.
+
+A tree is visible if all of the other trees between it and an edge of the grid are shorter 
+than it. Only consider trees in the same row or column; that is, only look up, down, left, 
+or right from any given tree.
+
+All of the trees around the edge of the grid are visible - since they are already on the edge, 
+there are no trees to block the view. In this example, that only leaves the interior nine 
+trees to consider:
+
+    The top-left 5 is visible from the left and top. (It isn't visible from the right or bottom 
+        since other trees of height 5 are in the way.)
+    The top-middle 5 is visible from the top and right.
+    The top-right 1 is not visible from any direction; for it to be visible, 
+        there would need to only be trees of height 0 between it and an edge.
+    The left-middle 5 is visible, but only from the right.
+    The center 3 is not visible from any direction; for it to be visible, 
+        there would need to be only trees of at most height 2 between it and an edge.
+    The right-middle 3 is visible from the right.
+    In the bottom row, the middle 5 is visible, but the 3 and 4 are not.
+
+With 16 trees visible on the edge and another 5 visible in the interior, 
+    a total of 21 trees are visible in this arrangement.
+
+Consider your map; how many trees are visible from outside the grid?
+*/
+
+
+use common::log::{self, Level};
+use common::parse::lines;
+use common::{trace, verbose, ParseError, Solution};
+use grid::{Grid, View};
+
+mod generate;
+mod simulate;
+
+// Read the input text into a 2D array of tree heights
+fn read_input(text: &str) -> Result<Grid<u8>, ParseError> {
+    let mut rows: Vec<Vec<u8>> = Vec::new();
+    for mut line in lines(text) {
+        let mut row = Vec::new();
+        while !line.at_end() {
+            let c = line.char_matching("a tree height from 0-9", |c| c.is_ascii_digit())?;
+            row.push(c.to_digit(10).unwrap() as u8);
+        }
+        if row.is_empty() {
+            return Err(line.error("a tree height from 0-9"));
+        }
+        // every row must be as wide as the first
+        let width = rows.first().map_or(row.len(), |first| first.len());
+        if row.len() != width {
+            return Err(line.error_at(0, &format!("a row of {} trees", width)));
+        }
+        rows.push(row);
+    }
+    Ok(Grid::from_rows(rows).unwrap())
+}
+
+// print the grid
+fn print_grid(grid: &Grid<u8>) {
+    if !log::enabled(Level::Trace) {
+        return;
+    }
+    // header
+    trace!("---");
+    trace!("{}", grid.render(|&height| char::from(b'0' + height)));
+    trace!("---");
+}
+
+// compute visibility from west,
+// marking visible trees in vis (which is not rotated with the view)
+fn find_vis_from_west(view: &View<u8>, vis: &mut Grid<u8>) {
+    for y in 0..view.height() {
+        find_vis_from_west_in_row(view, vis, y);
+    }
+}
+
+// compute visibility from west along one row of the view
+fn find_vis_from_west_in_row(view: &View<u8>, vis: &mut Grid<u8>, y: usize) {
+    let mut max = -1;
+    for x in 0..view.width() {
+        if view[(x, y)] as i32 > max {
+            vis[view.position((x, y))] = 1;
+            max = view[(x, y)] as i32;
+        }
+    }
+}
+
+// compute visibility from all directions
+// by looking at the grid from west after rotating it 3 times
+// and accumulating the visibility
+fn find_vis(grid: &Grid<u8>) -> Grid<u8> {
+    let mut vis = Grid::new(grid.width(), grid.height(), 0);
+    let mut view = grid.view();
+    for _ in 0..4 {
+        find_vis_from_west(&view, &mut vis);
+        view = view.rotate_cw();
+    }
+    vis
+}
+
+// compute visibility from a particular tree within the grid
+// for example in the grid:
+//  30373
+//  25512
+//  65332
+//  33549
+//  35390
+// the tree at (2, 1) can see 1 tree to the north, 2 trees to the east,
+// 2 trees to the south, and 1 tree to the west.
+// Return the visibility score, which is the product of the number of trees
+// seen in each direction (1 * 2 * 2 * 1 = 4)
+fn find_vis_from(grid: &Grid<u8>, vis: &mut Grid<u8>, (x, y): (usize, usize)) -> u32 {
+    let mut score = 1;
+    let my_height = grid[(x, y)];
+    // trees in each direction, nearest first
+    let north = (0..y).rev().map(|k| (x, k)).collect::<Vec<_>>();
+    let east = (x + 1..grid.width()).map(|k| (k, y)).collect::<Vec<_>>();
+    let south = (y + 1..grid.height()).map(|k| (x, k)).collect::<Vec<_>>();
+    let west = (0..x).rev().map(|k| (k, y)).collect::<Vec<_>>();
+    for direction in [north, east, south, west] {
+        let mut dist = 0;
+        for pos in direction {
+            vis[pos] = 1;
+            dist += 1;
+            if grid[pos] >= my_height {
+                break;
+            }
+        }
+        score *= dist;
+    }
+    score
+}
+
+// find the tree with the best visibility score
+fn find_best_vis(grid: &Grid<u8>) -> ((usize, usize), u32) {
+    let mut best = (0, 0);
+    let mut best_score = 0;
+    for pos in grid.positions() {
+        let score = find_vis_from(grid, &mut Grid::new(grid.width(), grid.height(), 0), pos);
+        if score > best_score {
+            best = pos;
+            best_score = score;
+        }
+    }
+    (best, best_score)
+}
+
+// sum the visibility
+fn sum_vis(vis: &Grid<u8>) -> u32 {
+    vis.iter().map(|(_, &c)| c as u32).sum()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Grid<u8>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
+
+    // number of trees visible from outside the grid
+    fn part1(grid: &Self::Input) -> u32 {
+        print_grid(grid);
+
+        if log::enabled(Level::Trace) {
+            let mut vis = Grid::new(grid.width(), grid.height(), 0);
+            find_vis_from_west(&grid.view(), &mut vis);
+            print_grid(&vis);
+        }
+
+        let vis = find_vis(grid);
+        print_grid(&vis);
+
+        sum_vis(&vis)
+    }
+
+    // highest scenic score of any tree
+    fn part2(grid: &Self::Input) -> u32 {
+        let ((x, y), score) = find_best_vis(grid);
+        verbose!("best tree at ({}, {}) with score {}", x, y, score);
+        score
+    }
+}
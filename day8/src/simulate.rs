@@ -0,0 +1,92 @@
+// the trees found visible so far, for the visualizer
+
+use std::rc::Rc;
+
+use common::simulate::{Frame, Simulation};
+use grid::Grid;
+
+use crate::{find_vis_from, find_vis_from_west_in_row, Day8};
+
+// the sides of the grid looked from, as the view is rotated clockwise
+const SIDES: [&str; 4] = ["west", "south", "east", "north"];
+
+pub struct Look {
+    heights: Rc<Grid<u8>>,
+    kind: LookKind,
+}
+
+enum LookKind {
+    // part 1: trees visible from outside after looking along a row
+    // the tree at the start of the row is kept in view
+    Row { side: usize, start: (usize, usize), vis: Grid<u8> },
+    // part 2: the trees one tree can see, and the best tree so far
+    Tree { tree: (usize, usize), score: u32, best: ((usize, usize), u32) },
+    Start,
+}
+
+impl Simulation for Day8 {
+    type State = Look;
+
+    fn record(grid: &Grid<u8>, part: u8) -> Vec<Look> {
+        let heights = Rc::new(grid.clone());
+        let look = |kind| Look { heights: heights.clone(), kind };
+        let mut looks = vec![look(LookKind::Start)];
+        if part == 1 {
+            let mut vis = Grid::new(grid.width(), grid.height(), 0);
+            let mut view = grid.view();
+            for side in 0..SIDES.len() {
+                for row in 0..view.height() {
+                    find_vis_from_west_in_row(&view, &mut vis, row);
+                    let start = view.position((0, row));
+                    looks.push(look(LookKind::Row { side, start, vis: vis.clone() }));
+                }
+                view = view.rotate_cw();
+            }
+        } else {
+            let mut best = ((0, 0), 0);
+            for tree in grid.positions() {
+                let mut seen = Grid::new(grid.width(), grid.height(), 0);
+                let score = find_vis_from(grid, &mut seen, tree);
+                if score > best.1 {
+                    best = (tree, score);
+                }
+                looks.push(look(LookKind::Tree { tree, score, best }));
+            }
+        }
+        looks
+    }
+
+    // trees show their height where they are visible, or '.' where not
+    fn frame(looks: &[Look], step: usize) -> Frame {
+        let look = &looks[step];
+        let heights = &look.heights;
+        let digits = |vis: &Grid<u8>| {
+            Grid::from_fn(heights.width(), heights.height(), |x, y| {
+                if vis[(x, y)] == 1 {
+                    char::from(b'0' + heights[(x, y)])
+                } else {
+                    '.'
+                }
+            })
+        };
+        match &look.kind {
+            LookKind::Start => Frame::new("tree heights", heights.map(|&height| char::from(b'0' + height))),
+            LookKind::Row { side, start, vis } => {
+                let visible = vis.iter().filter(|(_, &v)| v == 1).count();
+                let caption = format!("looking from the {}: {} trees visible", SIDES[*side], visible);
+                Frame::new(caption, digits(vis)).with_focus(*start)
+            }
+            LookKind::Tree { tree, score, best } => {
+                let mut seen = Grid::new(heights.width(), heights.height(), 0);
+                find_vis_from(heights, &mut seen, *tree);
+                let mut picture = digits(&seen);
+                picture[*tree] = '*';
+                let caption = format!(
+                    "tree at {:?} scores {}, best so far {:?} scores {}",
+                    tree, score, best.0, best.1
+                );
+                Frame::new(caption, picture).with_focus(*tree)
+            }
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use common::Solution;
+use day8::Day8;
+
+// the example without its last row, and the same turned on its side
+const WIDE: &str = "30373\n25512\n65332\n33549\n";
+const TALL: &str = "3263\n0556\n3533\n7135\n3224\n";
+
+#[test]
+fn forests_need_not_be_square() {
+    for text in [WIDE, TALL] {
+        let grid = Day8::parse(text).unwrap();
+        assert_eq!((Day8::part1(&grid), Day8::part2(&grid)), (18, 4), "{}", text);
+    }
+}
@@ -0,0 +1,24 @@
+// random series of rope head moves
+
+use common::generate::{Generator, Rng, StdRng};
+
+use crate::Day9;
+
+impl Generator for Day9 {
+    // number of moves
+    const DEFAULT_SIZE: usize = 2000;
+
+    fn generate(rng: &mut StdRng, size: usize) -> Vec<(char, i32)> {
+        let directions = ['R', 'U', 'L', 'D'];
+        (0..size)
+            .map(|_| (directions[rng.gen_range(0..4)], rng.gen_range(1..=20)))
+            .collect()
+    }
+
+    fn to_text(moves: &Vec<(char, i32)>) -> String {
+        moves
+            .iter()
+            .map(|(direction, distance)| format!("{} {}\n", direction, distance))
+            .collect()
+    }
+}
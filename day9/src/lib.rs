@@ -0,0 +1,224 @@
+use common::log::{self, Level};
+use common::parse::lines;
+use common::{trace, verbose, ParseError, Solution};
+use grid::SparseGrid;
+
+mod generate;
+mod simulate;
+
+struct Game {
+    knots: Vec<(i32, i32)>,
+    /// squares visited by last knot
+    visited: SparseGrid<()>,
+}
+
+impl Game {
+    fn new(num_knots: usize) -> Game {
+        let mut visited = SparseGrid::new();
+        // the origin is always shown when printing
+        visited.include((0, 0));
+        Game {
+            knots: vec![(0, 0); num_knots],
+            visited,
+        }
+    }
+
+    fn move_head(&mut self, direction: char, distance: i32) {
+        let head = &mut self.knots[0];
+        match direction {
+            'R' => head.0 += distance,
+            'U' => head.1 += distance,
+            'L' => head.0 -= distance,
+            'D' => head.1 -= distance,
+            _ => panic!("Invalid direction: {}", direction),
+        }
+    }
+
+    fn follow(&mut self, k: usize) {
+        // we only need to consider 3 cases:
+        // 1. head is two steps away from tail in a cardinal direction.
+        //    Tail moves one step to catch up:
+        //     H.T --> HT
+        // 2. head is two steps away in one direction and one step away in the other direction.
+        //    Tail moves one step diagonally to catch up:
+        //     H.. --> HT.
+        //     ..T     ...
+        // 3. head and tail are touching or overlapping
+        //    Tail stays in place.
+        // 4. head and tail are two steps away diagonally
+        //    Tail moves one step diagonally to catch up:
+        //     H..     H..
+        //     ... --> .T.
+        //     ..T     ...
+        // All other cases are impossible and should panic.
+
+        let head = self.knots[k - 1];
+        let tail = &mut self.knots[k];
+
+        trace!("follow: k={} head={:?} tail={:?}", k, head, tail);
+
+        // case 1
+        if (head.0 - tail.0).abs() == 2 && head.1 == tail.1 {
+            tail.0 += (head.0 - tail.0) / 2;
+        } else if (head.1 - tail.1).abs() == 2 && head.0 == tail.0 {
+            tail.1 += (head.1 - tail.1) / 2;
+        // case 2
+        } else if (head.0 - tail.0).abs() == 2 && (head.1 - tail.1).abs() == 1 {
+            tail.0 += (head.0 - tail.0) / 2;
+            tail.1 += head.1 - tail.1;
+        } else if (head.1 - tail.1).abs() == 2 && (head.0 - tail.0).abs() == 1 {
+            tail.0 += head.0 - tail.0;
+            tail.1 += (head.1 - tail.1) / 2;
+        // case 4
+        } else if (head.0 - tail.0).abs() == 2 && (head.1 - tail.1).abs() == 2 {
+            tail.0 += (head.0 - tail.0) / 2;
+            tail.1 += (head.1 - tail.1) / 2;
+        // case 3
+        } else {
+            // head and tail are touching or in the same row or column
+            // tail stays in place
+            assert!((head.0 - tail.0).abs() <= 1);
+            assert!((head.1 - tail.1).abs() <= 1);
+        }
+
+        trace!("  follow result: tail={:?}", tail);
+    }
+
+    fn visit(&mut self) {
+        let tail = self.knots[self.knots.len() - 1];
+        self.visited.insert(tail, ());
+    }
+
+    fn run(&mut self, moves: &[(char, i32)]) {
+        for &(direction, distance) in moves {
+            // print move
+            trace!("== {} {} ==", direction, distance);
+            trace!();
+            for _ in 0..distance {
+                self.move_head(direction, 1);
+                for k in 1..self.knots.len() {
+                    self.follow(k);
+                    //self.print();
+                }
+                self.visit();
+                //self.print();
+                trace!();
+            }
+        }
+    }
+    
+    /* print game state */
+    #[allow(dead_code)]
+    fn print(&self) {
+        if !log::enabled(Level::Trace) {
+            return;
+        }
+        let origin = |pos| if pos == (0, 0) { 's' } else { '.' };
+        trace!("{}", self.picture().render(|pos, c| c.copied().unwrap_or_else(|| origin(pos))));
+    }
+
+    // the grid with origin, all knots (H, 1, .. N), and visited squares,
+    // drawing the knots last so that they cover the rest,
+    // and the first knot last of all
+    fn picture(&self) -> SparseGrid<char> {
+        let mut grid = SparseGrid::new();
+        grid.include((0, 0));
+        // covering at least what the visited squares cover
+        if let Some(bounds) = self.visited.bounds() {
+            grid.include((bounds.min_x, bounds.min_y));
+            grid.include((bounds.max_x, bounds.max_y));
+        }
+        for (pos, _) in self.visited.iter() {
+            grid.insert(pos, '#');
+        }
+        for (k, &pos) in self.knots.iter().enumerate().rev() {
+            // print knot 0 as 'H' and others as digit
+            let c = if k == 0 { 'H' } else { char::from_digit(k as u32, 10).unwrap_or('*') };
+            grid.insert(pos, c);
+        }
+        grid
+    }
+
+    /* print visited squares, with origin indicated by 's',
+      and visited squares indicated by '#'
+
+      Example:
+        ..##..
+        ...##.
+        .####.
+        ....#.
+        s###..
+    */
+    fn print_visited(&self) {
+        if !log::enabled(Level::Trace) {
+            return;
+        }
+        // print the grid with origin and visited squares
+        let grid = self.visited.render(|pos, visited| {
+            if pos == (0, 0) {
+                's'
+            } else if visited.is_some() {
+                '#'
+            } else {
+                '.'
+            }
+        });
+        trace!("{}", grid);
+    }
+}
+
+fn read_moves(text: &str) -> Result<Vec<(char, i32)>, ParseError> {
+    let mut moves = Vec::new();
+    for mut line in lines(text) {
+        // example line: R 4
+        let direction = line.char_matching("a direction R, U, L or D", |c| "RULD".contains(c))?;
+        line.literal(" ")?;
+        let start = line.pos();
+        let distance = line.number::<i32>()?;
+        if distance < 0 {
+            return Err(line.error_at(start, "a distance of at least 0"));
+        }
+        line.end()?;
+        moves.push((direction, distance));
+    }
+    Ok(moves)
+}
+
+fn print_moves(moves: &[(char, i32)]) {
+    for (direction, distance) in moves {
+        trace!("{} {}", direction, distance);
+    }
+}
+
+// simulate a rope with the given number of knots,
+// returning the number of squares visited by the tail
+fn count_visited(moves: &[(char, i32)], num_knots: usize) -> usize {
+    print_moves(moves);
+    let mut game = Game::new(num_knots);
+    game.run(moves);
+    game.print_visited();
+    verbose!("{} knots: tail visited {} squares", num_knots, game.visited.len());
+    game.visited.len()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<(char, i32)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_moves(input)
+    }
+
+    // squares visited by the tail of a rope with a single tail knot
+    fn part1(moves: &Self::Input) -> usize {
+        count_visited(moves, 2)
+    }
+
+    // squares visited by the tail of a rope of 10 knots
+    fn part2(moves: &Self::Input) -> usize {
+        count_visited(moves, 10)
+    }
+}
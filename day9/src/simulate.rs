@@ -0,0 +1,70 @@
+// the knots after each step of the head, for the visualizer
+
+use common::simulate::{Frame, Simulation};
+use grid::SparseGrid;
+
+use crate::{Day9, Game};
+
+pub struct Step {
+    // the move the step is part of, if any
+    motion: Option<(char, i32)>,
+    knots: Vec<(i32, i32)>,
+}
+
+impl Simulation for Day9 {
+    type State = Step;
+
+    fn record(moves: &Vec<(char, i32)>, part: u8) -> Vec<Step> {
+        let mut game = Game::new(if part == 1 { 2 } else { 10 });
+        let mut states = vec![Step {
+            motion: None,
+            knots: game.knots.clone(),
+        }];
+        for &(direction, distance) in moves {
+            for _ in 0..distance {
+                game.move_head(direction, 1);
+                for k in 1..game.knots.len() {
+                    game.follow(k);
+                }
+                states.push(Step {
+                    motion: Some((direction, distance)),
+                    knots: game.knots.clone(),
+                });
+            }
+        }
+        states
+    }
+
+    fn frame(states: &[Step], step: usize) -> Frame {
+        // the tail has visited every square it was on up to this step,
+        // and the picture covers everywhere the rope goes so that it stays put
+        let mut visited = SparseGrid::new();
+        visited.include((0, 0));
+        for state in states {
+            for &knot in &state.knots {
+                visited.include(knot);
+            }
+        }
+        for state in &states[..=step] {
+            visited.insert(*state.knots.last().unwrap(), ());
+        }
+        let game = Game {
+            knots: states[step].knots.clone(),
+            visited,
+        };
+        let caption = match states[step].motion {
+            Some((direction, distance)) => format!("{} {}: {} squares visited", direction, distance, game.visited.len()),
+            None => "starting position".to_string(),
+        };
+        let picture = game.picture();
+        let origin = |pos| if pos == (0, 0) { 's' } else { '.' };
+        let text = picture.render(|pos, c| c.copied().unwrap_or_else(|| origin(pos)));
+        // rows are rendered from the lowest y, but up is up on the screen
+        let text = text.lines().rev().collect::<Vec<&str>>().join("\n");
+        let frame = Frame::from_text(caption, &text);
+        // keep the head in view
+        let bounds = picture.bounds().unwrap();
+        let head = game.knots[0];
+        frame.with_focus(((head.0 - bounds.min_x) as usize, (bounds.max_y - head.1) as usize))
+    }
+}
@@ -0,0 +1,88 @@
+/*
+Registry of the days: each day's solver, checker, timer, generator and
+recorder, looked up by number. It depends on nothing but the days, so
+that the WebAssembly module can have the solvers without the runner.
+*/
+
+use common::bench::{time, Timings};
+use common::generate::{generate, Generator};
+use common::simulate::{record, Recording};
+use common::{answer, check, solve, LoadError, ParseError, Source, Timed};
+
+// solves one part of a day given the input text
+pub type Solver = fn(&str, u8) -> Result<String, LoadError>;
+
+// loads a day's input once and solves the given parts,
+// with the time each answer (or failure to find one) took, loading included
+pub type Answerer = fn(&Source, &[u8]) -> Result<Vec<Timed>, LoadError>;
+
+// parses a day's input text, without solving it
+pub type Checker = fn(&str) -> Result<(), ParseError>;
+
+// times each phase of a day given the input text and number of runs
+pub type Timer = fn(&str, usize) -> Result<Timings, ParseError>;
+
+// generates puzzle text given a seed and size
+pub type Generate = fn(u64, usize) -> String;
+
+// records the simulation of one part given the input text
+pub type Recorder = fn(&str, u8) -> Result<Recording, ParseError>;
+
+pub struct Day {
+    pub day: u32,
+    pub solve: Solver,
+    pub answer: Answerer,
+    pub check: Checker,
+    pub time: Timer,
+    pub generate: Generate,
+    // size of generated inputs when none is given
+    pub default_size: usize,
+    // for days which can be shown in the visualizer or exported as images
+    pub record: Option<Recorder>,
+}
+
+macro_rules! day {
+    (@ $day:expr, $solution:ty, $record:expr) => {
+        Day {
+            day: $day,
+            solve: solve::<$solution>,
+            answer: answer::<$solution>,
+            check: check::<$solution>,
+            time: time::<$solution>,
+            generate: generate::<$solution>,
+            default_size: <$solution as Generator>::DEFAULT_SIZE,
+            record: $record,
+        }
+    };
+    ($day:expr, $solution:ty, Simulation) => {
+        day!(@ $day, $solution, Some(record::<$solution>))
+    };
+    ($day:expr, $solution:ty) => {
+        day!(@ $day, $solution, None)
+    };
+}
+
+// every available day, in order
+pub const DAYS: &[Day] = &[
+    day!(1, day1::Day1),
+    day!(2, day2::Day2),
+    day!(3, day3::Day3),
+    day!(4, day4::Day4),
+    day!(5, day5::Day5, Simulation),
+    day!(6, day6::Day6),
+    day!(7, day7::Day7),
+    day!(8, day8::Day8, Simulation),
+    day!(9, day9::Day9, Simulation),
+    day!(10, day10::Day10, Simulation),
+    day!(11, day11::Day11, Simulation),
+];
+
+// look up a day in the registry
+pub fn day(day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+// look up the solver for a given day
+pub fn solver(day: u32) -> Option<Solver> {
+    self::day(day).map(|d| d.solve)
+}
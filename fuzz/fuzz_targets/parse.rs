@@ -0,0 +1,20 @@
+/*
+Every parser must reject bad input with an error rather than panic.
+The first byte picks the day, and the rest is the puzzle text.
+
+    cargo +nightly fuzz run parse
+*/
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Some((&day, text)) = data.split_first() {
+        let day = &aoc::DAYS[day as usize % aoc::DAYS.len()];
+        let text = String::from_utf8_lossy(text);
+        if let Err(err) = (day.check)(&text) {
+            let _ = err.to_string();
+        }
+    }
+});
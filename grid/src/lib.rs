@@ -0,0 +1,177 @@
+/*
+Grids for 2D puzzles.
+
+Grid<T> is a dense, rectangular grid stored row by row in one vector.
+Cells are addressed by (x, y), x being the column and y the row,
+counting from the top left corner.
+
+    let mut grid = Grid::new(3, 2, '.');
+    grid[(2, 0)] = '#';
+    assert_eq!(grid.render(|&c| c), "..#\n...");
+
+Views look at a grid rotated or transposed without copying it, and
+SparseGrid holds cells scattered over an unbounded plane, keeping
+track of the bounds they cover.
+*/
+
+use std::ops::{Index, IndexMut};
+
+mod sparse;
+mod view;
+
+pub use sparse::{Bounds, SparseGrid};
+pub use view::View;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    // cells row by row, top row first
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    // grid with every cell set to the given value
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    // grid with each cell computed from its position
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+        Grid { width, height, cells }
+    }
+
+    // grid from a list of rows, which must all be the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let width = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+        if self.contains((x, y)) {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): (usize, usize)) -> Option<&mut T> {
+        if self.contains((x, y)) {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    // rows from top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    // every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    // every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    // the up to four cells sharing an edge with the given one
+    pub fn neighbors4(&self, (x, y): (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        const STEPS: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+        self.steps((x, y), &STEPS)
+    }
+
+    // the up to eight cells sharing an edge or a corner with the given one
+    pub fn neighbors8(&self, (x, y): (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        const STEPS: [(isize, isize); 8] = [(-1, -1), (0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0)];
+        self.steps((x, y), &STEPS)
+    }
+
+    // positions one step away in each direction, dropping those off the grid
+    fn steps<'a>(
+        &'a self,
+        (x, y): (usize, usize),
+        steps: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        steps.iter().filter_map(move |&(dx, dy)| {
+            let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+            self.contains(pos).then_some(pos)
+        })
+    }
+
+    // grid of the same size with each cell mapped
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    // view of the grid as it is, which can then be rotated or transposed
+    pub fn view(&self) -> View<'_, T> {
+        View::new(self)
+    }
+
+    // one line of text per row, with a character for each cell
+    pub fn render(&self, f: impl Fn(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&f).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(self.contains((x, y)), "({}, {}) is outside a {}x{} grid", x, y, self.width, self.height);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(self.contains((x, y)), "({}, {}) is outside a {}x{} grid", x, y, self.width, self.height);
+        &mut self.cells[y * self.width + x]
+    }
+}
@@ -0,0 +1,143 @@
+/*
+Sparse grids over the whole plane.
+
+Cells are kept in a hash map by (x, y), and may be anywhere, including
+at negative coordinates. The grid tracks the bounding box of the cells
+it has seen, which is what it renders.
+*/
+
+use std::collections::hash_map;
+use std::collections::HashMap;
+
+// inclusive bounding box
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bounds {
+    pub min_x: i32,
+    pub max_x: i32,
+    pub min_y: i32,
+    pub max_y: i32,
+}
+
+impl Bounds {
+    // bounds holding a single position
+    pub fn at((x, y): (i32, i32)) -> Bounds {
+        Bounds {
+            min_x: x,
+            max_x: x,
+            min_y: y,
+            max_y: y,
+        }
+    }
+
+    // grow the bounds to hold the given position
+    pub fn include(&mut self, (x, y): (i32, i32)) {
+        self.min_x = self.min_x.min(x);
+        self.max_x = self.max_x.max(x);
+        self.min_y = self.min_y.min(y);
+        self.max_y = self.max_y.max(y);
+    }
+
+    pub fn contains(&self, (x, y): (i32, i32)) -> bool {
+        (self.min_x..=self.max_x).contains(&x) && (self.min_y..=self.max_y).contains(&y)
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max_x - self.min_x) as usize + 1
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max_y - self.min_y) as usize + 1
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<(i32, i32), T>,
+    // None until something has been inserted or included
+    bounds: Option<Bounds>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    // set a cell, returning its previous value
+    pub fn insert(&mut self, pos: (i32, i32), value: T) -> Option<T> {
+        self.include(pos);
+        self.cells.insert(pos, value)
+    }
+
+    // grow the bounds to hold a position, without setting a cell there
+    pub fn include(&mut self, pos: (i32, i32)) {
+        match &mut self.bounds {
+            Some(bounds) => bounds.include(pos),
+            None => self.bounds = Some(Bounds::at(pos)),
+        }
+    }
+
+    pub fn get(&self, pos: (i32, i32)) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: (i32, i32)) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    // entry for a cell, for updating it in place
+    pub fn entry(&mut self, pos: (i32, i32)) -> hash_map::Entry<'_, (i32, i32), T> {
+        self.include(pos);
+        self.cells.entry(pos)
+    }
+
+    pub fn contains(&self, pos: (i32, i32)) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    // number of cells set
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    // the cells set, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = ((i32, i32), &T)> {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    // one line of text per row of the bounds, from min_y to max_y,
+    // with a character for each position whether set or not
+    pub fn render(&self, f: impl Fn((i32, i32), Option<&T>) -> char) -> String {
+        let Some(bounds) = self.bounds else {
+            return String::new();
+        };
+        (bounds.min_y..=bounds.max_y)
+            .map(|y| {
+                (bounds.min_x..=bounds.max_x)
+                    .map(|x| f((x, y), self.get((x, y))))
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl<T> FromIterator<((i32, i32), T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = ((i32, i32), T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for (pos, value) in iter {
+            grid.insert(pos, value);
+        }
+        grid
+    }
+}
@@ -0,0 +1,137 @@
+/*
+Rotated and transposed views of a grid.
+
+A view maps its own (x, y) positions onto positions in the underlying
+grid, as any combination of mirroring each axis and then swapping the
+axes. That covers all eight ways of turning and flipping a rectangle,
+and composing two of them is just a matter of updating the flags.
+*/
+
+use std::ops::Index;
+
+use crate::Grid;
+
+#[derive(Debug)]
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    // mirror x or y (in view coordinates), then swap x and y
+    flip_x: bool,
+    flip_y: bool,
+    swap: bool,
+}
+
+impl<'a, T> View<'a, T> {
+    pub fn new(grid: &'a Grid<T>) -> View<'a, T> {
+        View {
+            grid,
+            flip_x: false,
+            flip_y: false,
+            swap: false,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        if self.swap {
+            self.grid.height()
+        } else {
+            self.grid.width()
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        if self.swap {
+            self.grid.width()
+        } else {
+            self.grid.height()
+        }
+    }
+
+    // position in the underlying grid of a position in the view
+    pub fn position(&self, (x, y): (usize, usize)) -> (usize, usize) {
+        let x = if self.flip_x { self.width() - 1 - x } else { x };
+        let y = if self.flip_y { self.height() - 1 - y } else { y };
+        if self.swap {
+            (y, x)
+        } else {
+            (x, y)
+        }
+    }
+
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<&'a T> {
+        if x < self.width() && y < self.height() {
+            self.grid.get(self.position((x, y)))
+        } else {
+            None
+        }
+    }
+
+    // turned a quarter turn clockwise, so the left column becomes the top row
+    pub fn rotate_cw(self) -> View<'a, T> {
+        View {
+            grid: self.grid,
+            flip_x: !self.flip_y,
+            flip_y: self.flip_x,
+            swap: !self.swap,
+        }
+    }
+
+    // turned a quarter turn counterclockwise
+    pub fn rotate_ccw(self) -> View<'a, T> {
+        self.rotate_cw().rotate_cw().rotate_cw()
+    }
+
+    // mirrored in the main diagonal, so rows become columns
+    pub fn transpose(self) -> View<'a, T> {
+        View {
+            grid: self.grid,
+            flip_x: self.flip_y,
+            flip_y: self.flip_x,
+            swap: !self.swap,
+        }
+    }
+
+    // the cells of one row of the view, left to right
+    pub fn row(self, y: usize) -> impl Iterator<Item = &'a T> {
+        (0..self.width()).map(move |x| Index::index(self.grid, self.position((x, y))))
+    }
+
+    // copy the view into a grid of its own
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width(), self.height(), |x, y| self[(x, y)].clone())
+    }
+
+    pub fn render(&self, f: impl Fn(&T) -> char) -> String {
+        (0..self.height())
+            .map(|y| self.row(y).map(&f).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+// views only borrow the grid, so they can be copied whatever the cells are
+impl<T> Clone for View<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for View<'_, T> {}
+
+impl<T> Index<(usize, usize)> for View<'_, T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(
+            x < self.width() && y < self.height(),
+            "({}, {}) is outside a {}x{} view",
+            x,
+            y,
+            self.width(),
+            self.height()
+        );
+        &self.grid[self.position((x, y))]
+    }
+}
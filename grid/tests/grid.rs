@@ -0,0 +1,74 @@
+use grid::{Bounds, Grid, SparseGrid};
+
+// 3 wide, 2 high:
+//   abc
+//   def
+fn letters() -> Grid<char> {
+    Grid::from_rows(vec!["abc".chars().collect(), "def".chars().collect()]).unwrap()
+}
+
+#[test]
+fn dense_grids_are_row_major() {
+    let grid = letters();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(2, 0)], 'c');
+    assert_eq!(grid[(0, 1)], 'd');
+    assert_eq!(grid.get((3, 0)), None);
+    assert_eq!(grid.row(1), &['d', 'e', 'f']);
+    assert_eq!(grid.render(|&c| c), "abc\ndef");
+    assert_eq!(grid, Grid::from_fn(3, 2, |x, y| (b'a' + (3 * y + x) as u8) as char));
+    assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_none());
+}
+
+#[test]
+fn neighbors_stay_on_the_grid() {
+    let grid = letters();
+    let corner = grid.neighbors4((0, 0)).collect::<Vec<_>>();
+    assert_eq!(corner, vec![(1, 0), (0, 1)]);
+    let middle = grid.neighbors8((1, 0)).collect::<Vec<_>>();
+    assert_eq!(middle, vec![(2, 0), (2, 1), (1, 1), (0, 1), (0, 0)]);
+}
+
+#[test]
+fn views_rotate_and_transpose() {
+    let grid = letters();
+    let view = grid.view();
+    assert_eq!(view.rotate_cw().render(|&c| c), "da\neb\nfc");
+    assert_eq!(view.rotate_ccw().render(|&c| c), "cf\nbe\nad");
+    assert_eq!(view.rotate_cw().rotate_cw().render(|&c| c), "fed\ncba");
+    assert_eq!(view.transpose().render(|&c| c), "ad\nbe\ncf");
+    // four quarter turns, or two transpositions, are no turn at all
+    let turned = view.rotate_cw().rotate_cw().rotate_cw().rotate_cw();
+    assert_eq!(turned.to_grid(), grid);
+    assert_eq!(view.transpose().transpose().to_grid(), grid);
+    // views map back to positions in the grid
+    let rotated = view.rotate_cw();
+    assert_eq!((rotated.width(), rotated.height()), (2, 3));
+    assert_eq!(rotated.position((0, 0)), (0, 1));
+    assert_eq!(rotated[(1, 2)], 'c');
+    assert_eq!(rotated.get((2, 0)), None);
+}
+
+#[test]
+fn sparse_grids_track_bounds() {
+    let mut grid = SparseGrid::new();
+    assert_eq!(grid.bounds(), None);
+    assert_eq!(grid.render(|_, _| '.'), "");
+    grid.insert((0, 0), 's');
+    grid.insert((2, -1), '#');
+    grid.include((-1, 0));
+    assert_eq!(grid.len(), 2);
+    assert_eq!(
+        grid.bounds(),
+        Some(Bounds {
+            min_x: -1,
+            max_x: 2,
+            min_y: -1,
+            max_y: 0
+        })
+    );
+    assert_eq!(grid.render(|_, c| *c.unwrap_or(&'.')), "...#\n.s..");
+    *grid.entry((5, 5)).or_insert('x') = 'y';
+    assert_eq!(grid.get((5, 5)), Some(&'y'));
+    assert_eq!(grid.bounds().unwrap().width(), 7);
+}
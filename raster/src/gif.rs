@@ -0,0 +1,190 @@
+/*
+GIF encoding of images and animations.
+
+All frames share the palette of the first, which becomes the global
+colour table. Pixels are compressed with LZW, as GIF requires: codes
+stand for ever longer runs of pixels seen before, growing from the
+minimum code size up to 12 bits, after which the table is cleared and
+built again.
+*/
+
+use crate::Image;
+
+const MAX_CODES: u16 = 4096;
+
+pub struct Animation {
+    frames: Vec<(Image, u16)>,
+    // number of times to play, 0 for forever
+    repeat: u16,
+}
+
+impl Animation {
+    pub fn new() -> Animation {
+        Animation {
+            frames: Vec::new(),
+            repeat: 0,
+        }
+    }
+
+    pub fn repeat(mut self, repeat: u16) -> Animation {
+        self.repeat = repeat;
+        self
+    }
+
+    // add a frame shown for the given time in hundredths of a second.
+    // Frames must all be the same size as the first.
+    pub fn push(&mut self, image: Image, delay: u16) {
+        if let Some((first, _)) = self.frames.first() {
+            assert_eq!((image.width(), image.height()), (first.width(), first.height()), "frame size");
+        }
+        self.frames.push((image, delay));
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let Some((first, _)) = self.frames.first() else {
+            panic!("an animation needs at least one frame");
+        };
+        let bits = first.bits();
+        let mut gif = b"GIF89a".to_vec();
+
+        // logical screen, with a global colour table of 2^bits colours
+        gif.extend((first.width() as u16).to_le_bytes());
+        gif.extend((first.height() as u16).to_le_bytes());
+        gif.push(0x80 | ((bits - 1) << 4) | (bits - 1));
+        // background colour and pixel aspect ratio
+        gif.extend([0, 0]);
+        for i in 0..1 << bits {
+            let c = first.palette.get(i).copied().unwrap_or(crate::Rgb(0, 0, 0));
+            gif.extend([c.0, c.1, c.2]);
+        }
+
+        if self.frames.len() > 1 {
+            // application extension which makes the animation loop
+            gif.extend([0x21, 0xff, 11]);
+            gif.extend(b"NETSCAPE2.0");
+            gif.extend([3, 1]);
+            gif.extend(self.repeat.to_le_bytes());
+            gif.push(0);
+        }
+
+        for (image, delay) in &self.frames {
+            // graphic control extension, giving the delay
+            gif.extend([0x21, 0xf9, 4, 0]);
+            gif.extend(delay.to_le_bytes());
+            gif.extend([0, 0]);
+            // image descriptor covering the whole screen, using the global colours
+            gif.push(0x2c);
+            gif.extend([0, 0, 0, 0]);
+            gif.extend((image.width() as u16).to_le_bytes());
+            gif.extend((image.height() as u16).to_le_bytes());
+            gif.push(0);
+            // pixels, as LZW codes in sub-blocks of at most 255 bytes
+            let min_code_size = bits.max(2);
+            gif.push(min_code_size);
+            let pixels = image.pixels.rows().flatten().copied().collect::<Vec<u8>>();
+            for block in lzw(&pixels, min_code_size).chunks(255) {
+                gif.push(block.len() as u8);
+                gif.extend(block);
+            }
+            gif.push(0);
+        }
+
+        gif.push(0x3b);
+        gif
+    }
+}
+
+impl Default for Animation {
+    fn default() -> Animation {
+        Animation::new()
+    }
+}
+
+// a still image
+pub fn encode(image: &Image) -> Vec<u8> {
+    let mut animation = Animation::new();
+    animation.push(image.clone(), 0);
+    animation.encode()
+}
+
+// codes packed least significant bit first
+struct Bits {
+    bytes: Vec<u8>,
+    buffer: u32,
+    count: u8,
+}
+
+impl Bits {
+    fn push(&mut self, code: u16, width: u8) {
+        self.buffer |= (code as u32) << self.count;
+        self.count += width;
+        while self.count >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+fn lzw(pixels: &[u8], min_code_size: u8) -> Vec<u8> {
+    let clear = 1u16 << min_code_size;
+    let end = clear + 1;
+    let mut out = Bits { bytes: Vec::new(), buffer: 0, count: 0 };
+    // code for each (prefix code, pixel), or 0 where there is none yet
+    let mut table = vec![0u16; MAX_CODES as usize * clear as usize];
+    let index = |prefix: u16, pixel: u8| prefix as usize * clear as usize + pixel as usize;
+    let mut next = end + 1;
+    let mut width = min_code_size + 1;
+    out.push(clear, width);
+
+    let Some((&first, rest)) = pixels.split_first() else {
+        out.push(end, width);
+        return out.finish();
+    };
+    let mut prefix = first as u16;
+    for &pixel in rest {
+        let code = table[index(prefix, pixel)];
+        if code != 0 {
+            prefix = code;
+            continue;
+        }
+        out.push(prefix, width);
+        if next < MAX_CODES {
+            table[index(prefix, pixel)] = next;
+            next += 1;
+            // the decoder adds its entries one code behind us,
+            // so it widens its codes when it gets to the code before next
+            if next - 1 == 1 << width && width < 12 {
+                width += 1;
+            }
+        } else {
+            out.push(clear, width);
+            table.fill(0);
+            next = end + 1;
+            width = min_code_size + 1;
+        }
+        prefix = pixel as u16;
+    }
+    out.push(prefix, width);
+    // the decoder adds an entry for the last code too
+    if next == 1 << width && width < 12 {
+        width += 1;
+    }
+    out.push(end, width);
+    out.finish()
+}
@@ -0,0 +1,74 @@
+/*
+Pictures of the puzzles as image files, with no dependencies.
+
+An Image is a grid of indices into a palette of at most 256 colours,
+which is all the puzzles need, and is what both GIF and PNG (with a
+palette) store. Images are drawn from character grids by scaling each
+character up to a square cell of the colour given for it.
+
+    let picture = Grid::from_rows(vec![vec!['#', '.']]).unwrap();
+    let palette = [Rgb(0, 0, 0), Rgb(255, 255, 255)];
+    let image = Image::draw(&picture, 2, &palette, |&c| if c == '#' { 1 } else { 0 });
+    let png = png::encode(&image);
+
+GIF frames can be collected into an animation with gif::Animation.
+*/
+
+use grid::Grid;
+
+pub mod gif;
+pub mod png;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    pub palette: Vec<Rgb>,
+    // palette index of each pixel
+    pub pixels: Grid<u8>,
+}
+
+impl Image {
+    // draw each cell of the picture as a square of scale x scale pixels,
+    // in the palette colour chosen for it
+    pub fn draw<T>(picture: &Grid<T>, scale: usize, palette: &[Rgb], colour: impl Fn(&T) -> u8) -> Image {
+        assert!(!palette.is_empty() && palette.len() <= 256, "palette must have 1 to 256 colours");
+        let pixels = Grid::from_fn(picture.width() * scale, picture.height() * scale, |x, y| {
+            colour(&picture[(x / scale, y / scale)])
+        });
+        Image {
+            palette: palette.to_vec(),
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.pixels.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.pixels.height()
+    }
+
+    // copy of the image on a bigger canvas of the background colour,
+    // with the image at the top left
+    pub fn extend(&self, width: usize, height: usize, background: u8) -> Image {
+        let pixels = Grid::from_fn(width, height, |x, y| {
+            self.pixels.get((x, y)).copied().unwrap_or(background)
+        });
+        Image {
+            palette: self.palette.clone(),
+            pixels,
+        }
+    }
+
+    // number of bits needed for a palette index, as both formats count it
+    fn bits(&self) -> u8 {
+        let mut bits = 1;
+        while (1 << bits) < self.palette.len() {
+            bits += 1;
+        }
+        bits
+    }
+}
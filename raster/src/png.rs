@@ -0,0 +1,91 @@
+/*
+PNG encoding of images, using a palette and 8 bits per pixel.
+
+PNG compresses its pixels with zlib, but a zlib stream may also hold the
+data uncompressed in "stored" blocks of up to 65535 bytes, which is what
+we write. The files are bigger than they need be, but any viewer can
+read them and no compressor is needed.
+*/
+
+use crate::Image;
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+// largest stored block
+const BLOCK: usize = 65535;
+
+pub fn encode(image: &Image) -> Vec<u8> {
+    let mut png = SIGNATURE.to_vec();
+
+    let mut header = Vec::new();
+    header.extend((image.width() as u32).to_be_bytes());
+    header.extend((image.height() as u32).to_be_bytes());
+    // 8 bits per pixel, palette colour, deflate, adaptive filtering, no interlace
+    header.extend([8, 3, 0, 0, 0]);
+    chunk(&mut png, b"IHDR", &header);
+
+    let palette = image.palette.iter().flat_map(|c| [c.0, c.1, c.2]).collect::<Vec<u8>>();
+    chunk(&mut png, b"PLTE", &palette);
+
+    // every row starts with its filter type, 0 being none
+    let mut raw = Vec::with_capacity((image.width() + 1) * image.height());
+    for row in image.pixels.rows() {
+        raw.push(0);
+        raw.extend(row);
+    }
+    chunk(&mut png, b"IDAT", &zlib_stored(&raw));
+
+    chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+// length, type, data and a checksum of the type and data
+fn chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+// zlib stream of uncompressed deflate blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    // deflate with a 32K window, no preset dictionary, and a valid check
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(BLOCK).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        // the block header is a single bit saying whether this is the last
+        // block, and two bits of zero for a stored block
+        out.push(blocks.peek().is_none() as u8);
+        let len = block.len() as u16;
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(block);
+    }
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+pub fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xedb8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+pub fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
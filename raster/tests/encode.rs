@@ -0,0 +1,180 @@
+use grid::Grid;
+use raster::gif::Animation;
+use raster::{gif, png, Image, Rgb};
+
+// pseudo-random image, noisy enough to fill the LZW table several times
+fn noise(width: usize, height: usize, colours: usize) -> Image {
+    let mut seed = 12345u32;
+    let pixels = Grid::from_fn(width, height, |x, _| {
+        seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+        // runs of the same colour as well as noise
+        if x % 7 == 0 {
+            0
+        } else {
+            ((seed >> 16) as usize % colours) as u8
+        }
+    });
+    let palette = (0..colours).map(|i| Rgb(i as u8, 255 - i as u8, 0)).collect();
+    Image { palette, pixels }
+}
+
+// the pixels of every frame, decoding the LZW codes of a GIF
+fn decode_gif(gif: &[u8]) -> Vec<Vec<u8>> {
+    assert_eq!(&gif[..6], b"GIF89a");
+    let bits = (gif[10] & 7) + 1;
+    let mut pos = 13 + 3 * (1 << bits);
+    let mut frames = Vec::new();
+    loop {
+        match gif[pos] {
+            0x21 => {
+                // extension: label and sub-blocks
+                pos += 2;
+                while gif[pos] != 0 {
+                    pos += gif[pos] as usize + 1;
+                }
+                pos += 1;
+            }
+            0x2c => {
+                let min_code_size = gif[pos + 10];
+                pos += 11;
+                let mut data = Vec::new();
+                while gif[pos] != 0 {
+                    data.extend(&gif[pos + 1..pos + 1 + gif[pos] as usize]);
+                    pos += gif[pos] as usize + 1;
+                }
+                pos += 1;
+                frames.push(unlzw(&data, min_code_size));
+            }
+            0x3b => return frames,
+            other => panic!("unexpected block {:#x}", other),
+        }
+    }
+}
+
+fn unlzw(data: &[u8], min_code_size: u8) -> Vec<u8> {
+    let clear = 1usize << min_code_size;
+    let mut table: Vec<Vec<u8>> = Vec::new();
+    let mut width = min_code_size + 1;
+    let mut previous: Option<Vec<u8>> = None;
+    let mut out = Vec::new();
+    let mut bit = 0;
+    loop {
+        let mut code = 0;
+        for i in 0..width as usize {
+            let b = bit + i;
+            code |= ((data[b / 8] >> (b % 8)) as usize & 1) << i;
+        }
+        bit += width as usize;
+        if code == clear {
+            table = (0..clear).map(|i| vec![i as u8]).collect();
+            table.push(Vec::new());
+            table.push(Vec::new());
+            width = min_code_size + 1;
+            previous = None;
+            continue;
+        }
+        if code == clear + 1 {
+            return out;
+        }
+        let entry = match &previous {
+            None => table[code].clone(),
+            Some(previous) => {
+                let entry = if code < table.len() {
+                    table[code].clone()
+                } else {
+                    assert_eq!(code, table.len(), "code out of range");
+                    let mut entry = previous.clone();
+                    entry.push(previous[0]);
+                    entry
+                };
+                if table.len() < 4096 {
+                    let mut added = previous.clone();
+                    added.push(entry[0]);
+                    table.push(added);
+                    if table.len() == 1 << width && width < 12 {
+                        width += 1;
+                    }
+                }
+                entry
+            }
+        };
+        out.extend(&entry);
+        previous = Some(entry);
+    }
+}
+
+#[test]
+fn gif_frames_decode_to_their_pixels() {
+    let mut animation = Animation::new();
+    let images = [noise(300, 200, 16), noise(300, 200, 3), noise(300, 200, 1)];
+    for image in &images {
+        animation.push(image.clone(), 10);
+    }
+    let frames = decode_gif(&animation.encode());
+    assert_eq!(frames.len(), 3);
+    for (frame, image) in frames.iter().zip(&images) {
+        let pixels = image.pixels.rows().flatten().copied().collect::<Vec<u8>>();
+        assert!(frame == &pixels);
+    }
+}
+
+#[test]
+fn tiny_gif() {
+    let image = noise(1, 1, 2);
+    assert_eq!(decode_gif(&gif::encode(&image)), vec![vec![image.pixels[(0, 0)]]]);
+}
+
+#[test]
+fn png_holds_rows_of_pixels() {
+    let image = noise(400, 300, 200);
+    let png = png::encode(&image);
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    // walk the chunks, checking their checksums
+    let mut pos = 8;
+    let mut kinds = Vec::new();
+    let mut zlib = Vec::new();
+    while pos < png.len() {
+        let len = u32::from_be_bytes(png[pos..pos + 4].try_into().unwrap()) as usize;
+        let body = &png[pos + 4..pos + 8 + len];
+        let crc = u32::from_be_bytes(png[pos + 8 + len..pos + 12 + len].try_into().unwrap());
+        assert_eq!(png::crc32(body), crc);
+        kinds.push(String::from_utf8(body[..4].to_vec()).unwrap());
+        if &body[..4] == b"IDAT" {
+            zlib.extend(&body[4..]);
+        }
+        pos += len + 12;
+    }
+    assert_eq!(kinds, ["IHDR", "PLTE", "IDAT", "IEND"]);
+
+    // stored deflate blocks
+    let mut raw = Vec::new();
+    let mut pos = 2;
+    loop {
+        let last = zlib[pos] & 1 == 1;
+        let len = u16::from_le_bytes([zlib[pos + 1], zlib[pos + 2]]) as usize;
+        raw.extend(&zlib[pos + 5..pos + 5 + len]);
+        pos += 5 + len;
+        if last {
+            break;
+        }
+    }
+    assert_eq!(u32::from_be_bytes(zlib[pos..].try_into().unwrap()), png::adler32(&raw));
+    for (y, row) in raw.chunks(401).enumerate() {
+        assert_eq!(row[0], 0);
+        assert_eq!(&row[1..], image.pixels.row(y));
+    }
+}
+
+#[test]
+fn pictures_are_scaled_up() {
+    let picture = Grid::from_rows(vec![vec!['#', '.'], vec!['.', '#']]).unwrap();
+    let palette = [Rgb(0, 0, 0), Rgb(255, 255, 255)];
+    let image = Image::draw(&picture, 3, &palette, |&c| (c == '#') as u8);
+    assert_eq!((image.width(), image.height()), (6, 6));
+    assert_eq!(image.pixels[(2, 2)], 1);
+    assert_eq!(image.pixels[(3, 2)], 0);
+    assert_eq!(image.pixels[(5, 5)], 1);
+    let bigger = image.extend(8, 7, 0);
+    assert_eq!((bigger.width(), bigger.height()), (8, 7));
+    assert_eq!(bigger.pixels[(7, 6)], 0);
+}
@@ -0,0 +1,27 @@
+// JavaScript side of the solvers' WebAssembly module (see src/lib.rs).
+//
+//     import { load } from "./solve.mjs";
+//     const { solve } = await load(bytes);
+//     solve(1, 2, input);  // the answer, or throws an Error saying what is wrong with the input
+
+export async function load(bytes) {
+  const { instance } = await WebAssembly.instantiate(bytes, {});
+  const wasm = instance.exports;
+  const encoder = new TextEncoder();
+  const decoder = new TextDecoder();
+
+  function solve(day, part, input) {
+    const text = encoder.encode(input);
+    const ptr = wasm.alloc(text.length);
+    // the memory may have grown, so look at it afresh after every call
+    new Uint8Array(wasm.memory.buffer, ptr, text.length).set(text);
+    const status = wasm.solve_part(day, part, ptr, text.length);
+    const output = decoder.decode(new Uint8Array(wasm.memory.buffer, wasm.output_ptr(), wasm.output_len()));
+    if (status !== 0) {
+      throw new Error(output);
+    }
+    return output;
+  }
+
+  return { solve };
+}
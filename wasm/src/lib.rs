@@ -0,0 +1,92 @@
+/*
+The solvers as a WebAssembly module, for running in a browser or node.
+
+Build with
+
+    cargo build --release -p wasm --target wasm32-unknown-unknown
+
+which makes target/wasm32-unknown-unknown/release/wasm.wasm, and load it
+with wasm/solve.mjs, which wraps it up as the JavaScript function
+
+    solve(day, part, input) -> string
+
+throwing an Error with the message if the input does not parse or
+has no answer.
+wasm/test.mjs checks the module against the example answers in node:
+
+    node wasm/test.mjs target/wasm32-unknown-unknown/release/wasm.wasm
+
+Strings cross between JavaScript and the module as UTF-8 bytes in the
+module's memory. JavaScript asks for room for the input with alloc,
+copies the input there and calls solve_part, which takes ownership of
+the input and keeps the answer (or error message) for output_ptr and
+output_len to find until the next call.
+*/
+
+use std::cell::RefCell;
+
+thread_local! {
+    // the answer or error message from the last call to solve_part
+    static OUTPUT: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+// the answer to one part of a day's puzzle
+pub fn solve(day: u32, part: u8, input: &str) -> Result<String, String> {
+    let day = days::day(day).ok_or_else(|| format!("day {} is not available", day))?;
+    if part != 1 && part != 2 {
+        return Err(format!("there is no part {}", part));
+    }
+    (day.solve)(input, part).map_err(|err| err.to_string())
+}
+
+// room for len bytes, for JavaScript to write the input into
+#[no_mangle]
+pub extern "C" fn alloc(len: usize) -> *mut u8 {
+    let mut buffer = Vec::<u8>::with_capacity(len);
+    let ptr = buffer.as_mut_ptr();
+    std::mem::forget(buffer);
+    ptr
+}
+
+/// Give back room from alloc which was not passed to solve_part.
+///
+/// # Safety
+///
+/// ptr and len must be from a call to alloc, and not used again.
+#[no_mangle]
+pub unsafe extern "C" fn dealloc(ptr: *mut u8, len: usize) {
+    drop(Vec::from_raw_parts(ptr, 0, len));
+}
+
+/// Solve one part of a day given the input written to room from alloc,
+/// returning 0 with the answer as the output, or 1 with an error message.
+///
+/// # Safety
+///
+/// input must be from a call to alloc for len bytes, which have been written.
+/// The room is freed, so it must not be used again.
+#[no_mangle]
+pub unsafe extern "C" fn solve_part(day: u32, part: u32, input: *mut u8, len: usize) -> u32 {
+    let bytes = Vec::from_raw_parts(input, len, len);
+    let result = match String::from_utf8(bytes) {
+        Ok(input) => solve(day, part.min(u8::MAX as u32) as u8, &input),
+        Err(_) => Err("the input is not UTF-8".to_string()),
+    };
+    let (status, output) = match result {
+        Ok(answer) => (0, answer),
+        Err(message) => (1, message),
+    };
+    OUTPUT.with(|cell| *cell.borrow_mut() = output);
+    status
+}
+
+// where the output of the last call to solve_part is
+#[no_mangle]
+pub extern "C" fn output_ptr() -> *const u8 {
+    OUTPUT.with(|cell| cell.borrow().as_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn output_len() -> usize {
+    OUTPUT.with(|cell| cell.borrow().len())
+}
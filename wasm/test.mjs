@@ -0,0 +1,72 @@
+// Check the WebAssembly build of the solvers against the example answers:
+//
+//     cargo build --release -p wasm --target wasm32-unknown-unknown
+//     node wasm/test.mjs target/wasm32-unknown-unknown/release/wasm.wasm
+//
+// Run from the top of the repository, where the inputs are.
+
+import { readFileSync } from "node:fs";
+import { load } from "./solve.mjs";
+
+const CRT = [
+  "##..##..##..##..##..##..##..##..##..##..",
+  "###...###...###...###...###...###...###.",
+  "####....####....####....####....####....",
+  "#####.....#####.....#####.....#####.....",
+  "######......######......######......####",
+  "#######.......#######.......#######.....",
+].join("\n");
+
+// [day, part 1 answer, part 2 answer] for each day's example in the archive
+const EXAMPLES = [
+  [2, "15", "12"],
+  [3, "157", "70"],
+  [5, "CMZ", "MCD"],
+  [7, "95437", "24933642"],
+  [8, "21", "8"],
+  [9, "13", "1"],
+  [10, "13140", CRT],
+  [11, "10605", "2713310158"],
+];
+
+// the days without one, with the puzzle's example written out
+const INLINE = [
+  [1, "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n", "24000", "45000"],
+  [4, "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8\n", "2", "4"],
+  [6, "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n", "7", "19"],
+];
+
+const { solve } = await load(readFileSync(process.argv[2]));
+let failures = 0;
+const examples = EXAMPLES.map(([day, ...answers]) => [day, readFileSync(`inputs/2022/day${day}/example`, "utf8"), ...answers]);
+for (const [day, input, ...answers] of [...examples, ...INLINE]) {
+  answers.forEach((expected, i) => {
+    const answer = solve(day, i + 1, input);
+    if (answer !== expected) {
+      console.log(`day ${day} part ${i + 1}: expected ${expected}, got ${answer}`);
+      failures++;
+    }
+  });
+}
+
+// errors, in the input or in finding an answer, come back as exceptions
+const ERRORS = [
+  [9, 1, "X 1\n", "direction"],
+  // isize is 32 bits in WebAssembly, so two elves at its largest overflow
+  [1, 2, "2147483647\n\n2147483647\n", "overflow"],
+];
+for (const [day, part, input, expected] of ERRORS) {
+  try {
+    solve(day, part, input);
+    console.log(`day ${day} part ${part} gave an answer instead of an error`);
+    failures++;
+  } catch (err) {
+    if (!err.message.includes(expected)) {
+      console.log(`unexpected error from day ${day}: ${err.message}`);
+      failures++;
+    }
+  }
+}
+
+console.log(failures === 0 ? "ok" : `${failures} failed`);
+process.exit(failures === 0 ? 0 : 1);
@@ -0,0 +1,43 @@
+// The module's exports, called the way wasm/solve.mjs calls them,
+// but natively; wasm/test.mjs runs the WebAssembly build itself.
+
+use std::fs;
+
+use aoc::answers::ANSWERS;
+use aoc::input_path;
+
+// what solve.mjs does: copy the input in, solve, and read the output
+fn call(day: u32, part: u32, input: &str) -> (u32, String) {
+    unsafe {
+        let ptr = wasm::alloc(input.len());
+        std::ptr::copy_nonoverlapping(input.as_ptr(), ptr, input.len());
+        let status = wasm::solve_part(day, part, ptr, input.len());
+        let output = std::slice::from_raw_parts(wasm::output_ptr(), wasm::output_len());
+        (status, String::from_utf8(output.to_vec()).unwrap())
+    }
+}
+
+#[test]
+fn exports_give_the_recorded_answers() {
+    for answer in ANSWERS.iter().filter(|answer| answer.input == "example") {
+        let input = fs::read_to_string(input_path(answer.day, answer.input)).unwrap();
+        let (status, output) = call(answer.day, answer.part as u32, &input);
+        assert_eq!((status, output.as_str()), (0, answer.expected), "day {} part {}", answer.day, answer.part);
+    }
+}
+
+#[test]
+fn errors_are_messages() {
+    let (status, output) = call(9, 1, "X 1\n");
+    assert_eq!(status, 1);
+    assert!(output.contains("a direction R, U, L or D"), "{}", output);
+    assert_eq!(call(12, 1, ""), (1, "day 12 is not available".to_string()));
+    assert_eq!(call(1, 3, ""), (1, "there is no part 3".to_string()));
+    assert_eq!(wasm::solve(2, 1, "A Y\n"), Ok("8".to_string()));
+}
+
+#[test]
+fn empty_input() {
+    assert_eq!(call(1, 1, ""), (0, "0".to_string()));
+    unsafe { wasm::dealloc(wasm::alloc(10), 10) };
+}